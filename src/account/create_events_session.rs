@@ -0,0 +1,38 @@
+#[cfg(feature = "blocking")]
+use crate::blocking;
+use crate::{market_data::create_events_session::StreamSessionRoot, Result, TradierClient};
+
+#[cfg(feature = "blocking")]
+impl blocking::TradierClient {
+    pub fn create_account_events_session(&self) -> Result<StreamSessionRoot> {
+        let request = self.build_request_post("accounts/events/session", None::<()>, None::<()>);
+        self.send(request)
+    }
+}
+
+impl TradierClient {
+    pub async fn create_account_events_session(&self) -> Result<StreamSessionRoot> {
+        let request = self.build_request_post("accounts/events/session", None::<()>, None::<()>);
+        self.send(request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::mock;
+
+    use crate::{TradierClient, TradierConfig};
+
+    #[tokio::test]
+    async fn test_create_account_events_session() {
+        let _m = mock("POST", "/v1/accounts/events/session")
+            .with_status(200)
+            .with_body(include_str!("test_requests/create_events_session.json"))
+            .create();
+
+        let client = TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        let session = client.create_account_events_session().await.unwrap().stream;
+        assert_eq!(session.sessionid, "b3a3f2cc-2f3a-4a2c-9d5f-44cb1bd1c0f1");
+    }
+}
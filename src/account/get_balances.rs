@@ -1,24 +1,21 @@
 #![allow(non_camel_case_types)]
 
-use eyre::Result;
 use optimistic_derives::*;
 use serde::{Deserialize, Serialize};
 
-use crate::{build_request_get, TradierConfig};
+#[cfg(feature = "blocking")]
+use crate::blocking;
+use crate::{Result, TradierClient};
 
 #[optimistic]
+#[derive(Default)]
 pub enum Type {
+    #[default]
     cash,
     margin,
     pdt,
 }
 
-impl Default for Type {
-    fn default() -> Self {
-        Type::cash
-    }
-}
-
 #[optimistic_no_ceho]
 pub struct Margin {
     pub fed_call: f64,
@@ -75,25 +72,42 @@ pub struct BalancesRoot {
     pub balances: Balances,
 }
 
-pub fn get_balances(config: &TradierConfig, account_id: String) -> Result<BalancesRoot> {
-    let response: BalancesRoot = build_request_get(
-        config,
-        &format!("accounts/{}/balances", account_id),
-        None::<()>,
-        None::<()>,
-    )
-    .send()?
-    .json()?;
-
-    Ok(response)
+#[cfg(feature = "blocking")]
+impl blocking::TradierClient {
+    pub fn get_balances(&self, account_id: String) -> Result<BalancesRoot> {
+        let request = self.build_request_get(
+            &format!("accounts/{}/balances", account_id),
+            None::<()>,
+            None::<()>,
+        );
+        let response: BalancesRoot = self.send(request)?;
+
+        Ok(response)
+    }
+}
+
+impl TradierClient {
+    pub async fn get_balances(&self, account_id: String) -> Result<BalancesRoot> {
+        let request = self.build_request_get(
+            &format!("accounts/{}/balances", account_id),
+            None::<()>,
+            None::<()>,
+        );
+        let response: BalancesRoot = self.send(request).await?;
+
+        Ok(response)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use mockito::mock;
 
-    use crate::{account::get_balances::get_balances, TradierConfig};
+    #[cfg(feature = "blocking")]
+    use crate::blocking;
+    use crate::{TradierClient, TradierConfig};
 
+    #[cfg(feature = "blocking")]
     #[test]
     fn test_get_user_profile() {
         let _m = mock("GET", "/v1/accounts/VA000000/balances")
@@ -101,13 +115,23 @@ mod tests {
             .with_body(include_str!("test_requests/get_balances.json"))
             .create();
 
-        let config = TradierConfig {
-            token: "xxx".into(),
-            endpoint: mockito::server_url(),
-        };
+        let client = blocking::TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        client.get_balances("VA000000".into()).unwrap();
+        let response = client.get_balances("VA000000".into());
+        assert!(response.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_get_balances_async() {
+        let _m = mock("GET", "/v1/accounts/VA000000/balances")
+            .with_status(200)
+            .with_body(include_str!("test_requests/get_balances.json"))
+            .create();
+
+        let client = TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
 
-        get_balances(&config, "VA000000".into()).unwrap();
-        let response = get_balances(&config, "VA000000".into());
+        let response = client.get_balances("VA000000".into()).await;
         assert!(response.is_ok());
     }
 }
@@ -0,0 +1,242 @@
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+
+use chrono::{DateTime, NaiveDate, Utc};
+use optimistic_derives::*;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "blocking")]
+use crate::blocking;
+use crate::{Result, TradierClient};
+
+#[optimistic]
+pub enum SortBy {
+    openDate,
+    closeDate,
+}
+
+#[optimistic]
+pub enum SortOrder {
+    asc,
+    desc,
+}
+
+/// A closed position. `term` is the number of days it was held.
+#[optimistic_no_ceho]
+pub struct ClosedPosition {
+    pub close_date: DateTime<Utc>,
+    pub cost: f64,
+    pub gain_loss: f64,
+    pub gain_loss_percent: f64,
+    pub open_date: DateTime<Utc>,
+    pub proceeds: f64,
+    pub quantity: f64,
+    pub symbol: String,
+    pub term: i64,
+}
+
+#[optimistic_no_ceho]
+#[serde(untagged)]
+enum ClosedPositionEnum {
+    Unit(ClosedPosition),
+    Vec(Vec<ClosedPosition>),
+}
+
+#[optimistic_no_ceho]
+struct NaiveGainLoss {
+    closed_position: Option<ClosedPositionEnum>,
+}
+
+#[optimistic_no_ceho]
+struct NaiveGainLossRoot {
+    gainloss: Option<NaiveGainLoss>,
+}
+
+#[optimistic_no_ceho]
+pub struct GainLoss {
+    pub closed_position: Vec<ClosedPosition>,
+}
+
+#[optimistic_no_ceho]
+pub struct GainLossRoot {
+    pub gainloss: GainLoss,
+}
+
+impl From<NaiveGainLossRoot> for GainLossRoot {
+    fn from(item: NaiveGainLossRoot) -> Self {
+        let closed_position = match item.gainloss.and_then(|g| g.closed_position) {
+            Some(ClosedPositionEnum::Unit(unit)) => vec![unit],
+            Some(ClosedPositionEnum::Vec(positions)) => positions,
+            None => vec![],
+        };
+        GainLossRoot {
+            gainloss: GainLoss { closed_position },
+        }
+    }
+}
+
+#[optimistic_no_c]
+struct Query {
+    page: Option<u64>,
+    limit: Option<u64>,
+    sortBy: Option<SortBy>,
+    sort: Option<SortOrder>,
+    start: Option<NaiveDate>,
+    end: Option<NaiveDate>,
+    symbol: Option<String>,
+}
+
+#[cfg(feature = "blocking")]
+impl blocking::TradierClient {
+    #[allow(clippy::too_many_arguments)]
+    pub fn get_gainloss(
+        &self,
+        account_id: String,
+        page: Option<u64>,
+        limit: Option<u64>,
+        sort_by: Option<SortBy>,
+        sort: Option<SortOrder>,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+        symbol: Option<String>,
+    ) -> Result<GainLossRoot> {
+        let query = Query {
+            page,
+            limit,
+            sortBy: sort_by,
+            sort,
+            start,
+            end,
+            symbol,
+        };
+
+        let request = self.build_request_get(
+            &format!("accounts/{}/gainloss", account_id),
+            None::<()>,
+            Some(query),
+        );
+        let response: NaiveGainLossRoot = self.send(request)?;
+
+        Ok(response.into())
+    }
+}
+
+impl TradierClient {
+    #[allow(clippy::too_many_arguments)]
+    pub async fn get_gainloss(
+        &self,
+        account_id: String,
+        page: Option<u64>,
+        limit: Option<u64>,
+        sort_by: Option<SortBy>,
+        sort: Option<SortOrder>,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+        symbol: Option<String>,
+    ) -> Result<GainLossRoot> {
+        let query = Query {
+            page,
+            limit,
+            sortBy: sort_by,
+            sort,
+            start,
+            end,
+            symbol,
+        };
+
+        let request = self.build_request_get(
+            &format!("accounts/{}/gainloss", account_id),
+            None::<()>,
+            Some(query),
+        );
+        let response: NaiveGainLossRoot = self.send(request).await?;
+
+        Ok(response.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use mockito::mock;
+
+    #[cfg(feature = "blocking")]
+    use crate::blocking;
+    use crate::{
+        account::get_gainloss::{SortBy, SortOrder},
+        TradierClient, TradierConfig,
+    };
+
+    #[tokio::test]
+    async fn test_get_gainloss() {
+        let _m = mock(
+            "GET",
+            "/v1/accounts/VA000000/gainloss?page=2&limit=25&sortBy=closeDate&sort=desc&start=2018-01-01&end=2018-12-31",
+        )
+        .with_status(200)
+        .with_body(include_str!("test_requests/get_gainloss.json"))
+        .create();
+
+        let client = TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        let response = client
+            .get_gainloss(
+                "VA000000".into(),
+                Some(2),
+                Some(25),
+                Some(SortBy::closeDate),
+                Some(SortOrder::desc),
+                NaiveDate::from_ymd_opt(2018, 1, 1),
+                NaiveDate::from_ymd_opt(2018, 12, 31),
+                None,
+            )
+            .await
+            .unwrap();
+        let positions = response.gainloss.closed_position;
+        assert_eq!(positions.len(), 2);
+        assert_eq!(positions[0].gain_loss, -2.64);
+        assert_eq!(positions[0].term, 134);
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn test_get_gainloss_single_and_null() {
+        let client = blocking::TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        let _m = mock("GET", "/v1/accounts/VA000000/gainloss?symbol=SNAP")
+            .with_status(200)
+            .with_body(include_str!("test_requests/get_gainloss_single.json"))
+            .create();
+        let response = client
+            .get_gainloss(
+                "VA000000".into(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some("SNAP".into()),
+            )
+            .unwrap();
+        assert_eq!(response.gainloss.closed_position[0].symbol, "SNAP");
+
+        let _m = mock("GET", "/v1/accounts/VA000000/gainloss?symbol=XYZ")
+            .with_status(200)
+            .with_body(r#"{"gainloss":null}"#)
+            .create();
+        let response = client
+            .get_gainloss(
+                "VA000000".into(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some("XYZ".into()),
+            )
+            .unwrap();
+        assert!(response.gainloss.closed_position.is_empty());
+    }
+}
@@ -1,11 +1,12 @@
 #![allow(non_camel_case_types, clippy::upper_case_acronyms)]
 
 use chrono::{DateTime, Utc};
-use eyre::Result;
 use optimistic_derives::*;
 use serde::{Deserialize, Serialize};
 
-use crate::{build_request_get, TradierConfig};
+#[cfg(feature = "blocking")]
+use crate::blocking;
+use crate::{Result, TradierClient};
 
 #[optimistic]
 enum TradeType {
@@ -176,44 +177,80 @@ struct Query {
     symbol: Option<String>,
 }
 
-#[allow(clippy::too_many_arguments)]
-pub fn get_history(
-    config: &TradierConfig,
-    account_id: String,
-    page: Option<u64>,
-    limit: Option<u64>,
-    activity_type: Option<EventTypeEnum>,
-    start: Option<DateTime<Utc>>,
-    end: Option<DateTime<Utc>>,
-    symbol: Option<String>,
-) -> Result<HistoryRoot> {
-    let query = Query {
-        page,
-        limit,
-        activity_type,
-        start,
-        end,
-        symbol,
-    };
-
-    let response: HistoryEnum = build_request_get(
-        config,
-        &format!("accounts/{}/history", account_id),
-        None::<()>,
-        Some(query),
-    )
-    .send()?
-    .json()?;
-
-    Ok(response.into())
+#[cfg(feature = "blocking")]
+impl blocking::TradierClient {
+    #[allow(clippy::too_many_arguments)]
+    pub fn get_history(
+        &self,
+        account_id: String,
+        page: Option<u64>,
+        limit: Option<u64>,
+        activity_type: Option<EventTypeEnum>,
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+        symbol: Option<String>,
+    ) -> Result<HistoryRoot> {
+        let query = Query {
+            page,
+            limit,
+            activity_type,
+            start,
+            end,
+            symbol,
+        };
+
+        let request = self.build_request_get(
+            &format!("accounts/{}/history", account_id),
+            None::<()>,
+            Some(query),
+        );
+        let response: HistoryEnum = self.send(request)?;
+
+        Ok(response.into())
+    }
+}
+
+impl TradierClient {
+    #[allow(clippy::too_many_arguments)]
+    pub async fn get_history(
+        &self,
+        account_id: String,
+        page: Option<u64>,
+        limit: Option<u64>,
+        activity_type: Option<EventTypeEnum>,
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+        symbol: Option<String>,
+    ) -> Result<HistoryRoot> {
+        let query = Query {
+            page,
+            limit,
+            activity_type,
+            start,
+            end,
+            symbol,
+        };
+
+        let request = self.build_request_get(
+            &format!("accounts/{}/history", account_id),
+            None::<()>,
+            Some(query),
+        );
+        let response: HistoryEnum = self.send(request).await?;
+
+        Ok(response.into())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use mockito::mock;
 
-    use crate::{account::get_history::get_history, TradierConfig};
+    #[cfg(feature = "blocking")]
+    use crate::blocking;
+    use crate::{TradierClient, TradierConfig};
 
+    #[cfg(feature = "blocking")]
     #[test]
     fn test_get_history() {
         let _m = mock("GET", "/v1/accounts/VA000000/history")
@@ -221,24 +258,13 @@ mod tests {
             .with_body(include_str!("test_requests/get_history.json"))
             .create();
 
-        let config = TradierConfig {
-            token: "xxx".into(),
-            endpoint: mockito::server_url(),
-        };
+        let client = blocking::TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
 
-        let response = get_history(
-            &config,
-            "VA000000".into(),
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-        );
+        let response = client.get_history("VA000000".into(), None, None, None, None, None, None);
         assert!(response.is_ok());
     }
 
+    #[cfg(feature = "blocking")]
     #[test]
     fn test_get_history_single() {
         let _m = mock("GET", "/v1/accounts/VA000000/history")
@@ -246,21 +272,24 @@ mod tests {
             .with_body(include_str!("test_requests/get_history_single.json"))
             .create();
 
-        let config = TradierConfig {
-            token: "xxx".into(),
-            endpoint: mockito::server_url(),
-        };
+        let client = blocking::TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
 
-        let response = get_history(
-            &config,
-            "VA000000".into(),
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-        );
+        let response = client.get_history("VA000000".into(), None, None, None, None, None, None);
+        assert!(response.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_get_history_async() {
+        let _m = mock("GET", "/v1/accounts/VA000000/history")
+            .with_status(200)
+            .with_body(include_str!("test_requests/get_history.json"))
+            .create();
+
+        let client = TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        let response = client
+            .get_history("VA000000".into(), None, None, None, None, None, None)
+            .await;
         assert!(response.is_ok());
     }
 }
@@ -0,0 +1,107 @@
+#![allow(non_snake_case)]
+
+use optimistic_derives::*;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "blocking")]
+use crate::blocking;
+use crate::{account::get_orders::Order, Result, TradierClient};
+
+#[optimistic_no_ceho]
+pub struct OrderRoot {
+    pub order: Order,
+}
+
+#[optimistic_no_ceho]
+struct Query {
+    includeTags: bool,
+}
+
+#[cfg(feature = "blocking")]
+impl blocking::TradierClient {
+    pub fn get_order(
+        &self,
+        account_id: String,
+        order_id: u64,
+        include_tags: bool,
+    ) -> Result<OrderRoot> {
+        let query = Query {
+            includeTags: include_tags,
+        };
+
+        let request = self.build_request_get(
+            &format!("accounts/{}/orders/{}", account_id, order_id),
+            None::<()>,
+            Some(query),
+        );
+        self.send(request)
+    }
+}
+
+impl TradierClient {
+    pub async fn get_order(
+        &self,
+        account_id: String,
+        order_id: u64,
+        include_tags: bool,
+    ) -> Result<OrderRoot> {
+        let query = Query {
+            includeTags: include_tags,
+        };
+
+        let request = self.build_request_get(
+            &format!("accounts/{}/orders/{}", account_id, order_id),
+            None::<()>,
+            Some(query),
+        );
+        self.send(request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::mock;
+
+    #[cfg(feature = "blocking")]
+    use crate::blocking;
+    use crate::{Class, OrderStatus, TradierClient, TradierConfig};
+
+    #[tokio::test]
+    async fn test_get_order() {
+        let _m = mock(
+            "GET",
+            "/v1/accounts/VA000000/orders/123456?includeTags=true",
+        )
+        .with_status(200)
+        .with_body(include_str!("test_requests/get_order.json"))
+        .create();
+
+        let client = TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        let order = client
+            .get_order("VA000000".into(), 123456, true)
+            .await
+            .unwrap()
+            .order;
+        assert_eq!(order.class, Class::multileg);
+        assert_eq!(order.status, OrderStatus::filled);
+        assert_eq!(order.tag.as_deref(), Some("bull-put"));
+        assert_eq!(
+            order.find(123458).unwrap().option_symbol.as_deref(),
+            Some("SPY190621P00275000")
+        );
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn test_get_order_not_found() {
+        let _m = mock("GET", "/v1/accounts/VA000000/orders/1?includeTags=false")
+            .with_status(404)
+            .with_body("")
+            .create();
+
+        let client = blocking::TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        assert!(client.get_order("VA000000".into(), 1, false).is_err());
+    }
+}
@@ -2,11 +2,12 @@
 #![allow(non_snake_case)]
 
 use chrono::{DateTime, Utc};
-use eyre::Result;
 use optimistic_derives::*;
 use serde::{Deserialize, Serialize};
 
-use crate::{build_request_get, Class, Duration, OrderStatus, OrderType, Side, TradierConfig};
+#[cfg(feature = "blocking")]
+use crate::blocking;
+use crate::{Class, Duration, OrderStatus, OrderType, Result, Side, TradierClient};
 
 #[optimistic_no_ceho]
 pub struct Order {
@@ -19,6 +20,7 @@ pub struct Order {
     pub status: OrderStatus,
     pub duration: Duration,
     pub price: Option<f64>,
+    pub stop_price: Option<f64>,
     pub avg_fill_price: f64,
     pub exec_quantity: f64,
     pub last_fill_price: f64,
@@ -27,9 +29,29 @@ pub struct Order {
     pub create_date: DateTime<Utc>,
     pub transaction_date: DateTime<Utc>,
     pub class: Class,
+    pub option_symbol: Option<String>,
+    pub tag: Option<String>,
+    pub num_legs: Option<i64>,
+    pub strategy: Option<String>,
+    /// Child orders of `multileg`, `combo`, `oto`, `oco` and `otoco` orders.
+    /// An `otoco`'s second and third legs are its OCO pair.
     pub leg: Option<Vec<Order>>,
 }
 
+impl Order {
+    pub fn legs(&self) -> &[Order] {
+        self.leg.as_deref().unwrap_or_default()
+    }
+
+    /// Finds this order or one of its legs, at any depth, by id.
+    pub fn find(&self, id: u64) -> Option<&Order> {
+        if self.id == id {
+            return Some(self);
+        }
+        self.legs().iter().find_map(|leg| leg.find(id))
+    }
+}
+
 #[optimistic_no_ceho]
 pub struct Orders {
     pub order: Vec<Order>,
@@ -66,31 +88,46 @@ impl From<MaybeOrdersRoot> for OrdersRoot {
     }
 }
 
-pub fn get_orders(
-    config: &TradierConfig,
-    account_id: String,
-    includeTags: bool,
-) -> Result<OrdersRoot> {
-    let query = Query { includeTags };
-
-    let response: MaybeOrdersRoot = build_request_get(
-        config,
-        &format!("accounts/{}/orders", account_id),
-        None::<()>,
-        Some(query),
-    )
-    .send()?
-    .json()?;
-
-    Ok(response.into())
+#[cfg(feature = "blocking")]
+impl blocking::TradierClient {
+    pub fn get_orders(&self, account_id: String, includeTags: bool) -> Result<OrdersRoot> {
+        let query = Query { includeTags };
+
+        let request = self.build_request_get(
+            &format!("accounts/{}/orders", account_id),
+            None::<()>,
+            Some(query),
+        );
+        let response: MaybeOrdersRoot = self.send(request)?;
+
+        Ok(response.into())
+    }
+}
+
+impl TradierClient {
+    pub async fn get_orders(&self, account_id: String, includeTags: bool) -> Result<OrdersRoot> {
+        let query = Query { includeTags };
+
+        let request = self.build_request_get(
+            &format!("accounts/{}/orders", account_id),
+            None::<()>,
+            Some(query),
+        );
+        let response: MaybeOrdersRoot = self.send(request).await?;
+
+        Ok(response.into())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use mockito::mock;
 
-    use crate::{account::get_orders::get_orders, TradierConfig};
+    #[cfg(feature = "blocking")]
+    use crate::blocking;
+    use crate::{Class, OrderType, TradierClient, TradierConfig};
 
+    #[cfg(feature = "blocking")]
     #[test]
     fn test_get_orders() {
         let _m = mock("GET", "/v1/accounts/VA000000/orders?includeTags=false")
@@ -98,12 +135,41 @@ mod tests {
             .with_body(include_str!("test_requests/get_orders.json"))
             .create();
 
-        let config = TradierConfig {
-            token: "xxx".into(),
-            endpoint: mockito::server_url(),
-        };
+        let client = blocking::TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
 
-        let response = get_orders(&config, "VA000000".into(), false);
+        let response = client.get_orders("VA000000".into(), false);
         assert!(response.is_ok());
     }
+
+    #[tokio::test]
+    async fn test_get_orders_async() {
+        let _m = mock("GET", "/v1/accounts/VA000000/orders?includeTags=false")
+            .with_status(200)
+            .with_body(include_str!("test_requests/get_orders.json"))
+            .create();
+
+        let client = TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        let response = client.get_orders("VA000000".into(), false).await;
+        assert!(response.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_get_orders_otoco_legs() {
+        let _m = mock("GET", "/v1/accounts/VA000001/orders?includeTags=false")
+            .with_status(200)
+            .with_body(include_str!("test_requests/get_orders_otoco.json"))
+            .create();
+
+        let client = TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        let response = client.get_orders("VA000001".into(), false).await.unwrap();
+        let order = &response.orders.order[0];
+        assert_eq!(order.class, Class::otoco);
+        assert_eq!(order.legs().len(), 3);
+        let stop = order.find(1017).unwrap();
+        assert_eq!(stop.order_type, OrderType::stop);
+        assert_eq!(stop.stop_price, Some(275.0));
+        assert!(order.legs()[0].legs().is_empty());
+    }
 }
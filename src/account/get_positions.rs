@@ -1,11 +1,12 @@
 #![allow(non_camel_case_types)]
 
 use chrono::{DateTime, Utc};
-use eyre::Result;
 use optimistic_derives::*;
 use serde::{Deserialize, Serialize};
 
-use crate::{build_request_get, TradierConfig};
+#[cfg(feature = "blocking")]
+use crate::blocking;
+use crate::{Result, TradierClient};
 
 #[optimistic_no_ceho]
 pub struct Position {
@@ -66,25 +67,42 @@ impl From<PositionsEnum> for PositionsRoot {
     }
 }
 
-pub fn get_positions(config: &TradierConfig, account_id: String) -> Result<PositionsRoot> {
-    let response: PositionsEnum = build_request_get(
-        config,
-        &format!("accounts/{}/positions", account_id),
-        None::<()>,
-        None::<()>,
-    )
-    .send()?
-    .json()?;
-
-    Ok(response.into())
+#[cfg(feature = "blocking")]
+impl blocking::TradierClient {
+    pub fn get_positions(&self, account_id: String) -> Result<PositionsRoot> {
+        let request = self.build_request_get(
+            &format!("accounts/{}/positions", account_id),
+            None::<()>,
+            None::<()>,
+        );
+        let response: PositionsEnum = self.send(request)?;
+
+        Ok(response.into())
+    }
+}
+
+impl TradierClient {
+    pub async fn get_positions(&self, account_id: String) -> Result<PositionsRoot> {
+        let request = self.build_request_get(
+            &format!("accounts/{}/positions", account_id),
+            None::<()>,
+            None::<()>,
+        );
+        let response: PositionsEnum = self.send(request).await?;
+
+        Ok(response.into())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use mockito::mock;
 
-    use crate::{account::get_positions::get_positions, TradierConfig};
+    #[cfg(feature = "blocking")]
+    use crate::blocking;
+    use crate::{TradierClient, TradierConfig};
 
+    #[cfg(feature = "blocking")]
     #[test]
     fn test_get_positions() {
         let _m = mock("GET", "/v1/accounts/VA000000/positions")
@@ -92,15 +110,13 @@ mod tests {
             .with_body(include_str!("test_requests/get_positions.json"))
             .create();
 
-        let config = TradierConfig {
-            token: "xxx".into(),
-            endpoint: mockito::server_url(),
-        };
+        let client = blocking::TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
 
-        let response = get_positions(&config, "VA000000".into());
+        let response = client.get_positions("VA000000".into());
         assert!(response.is_ok());
     }
 
+    #[cfg(feature = "blocking")]
     #[test]
     fn test_get_positions_single() {
         let _m = mock("GET", "/v1/accounts/VA000000/positions")
@@ -108,13 +124,23 @@ mod tests {
             .with_body(include_str!("test_requests/get_positions_single.json"))
             .create();
 
-        let config = TradierConfig {
-            token: "xxx".into(),
-            endpoint: mockito::server_url(),
-        };
+        let client = blocking::TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        client.get_positions("VA000000".into()).unwrap();
+        let response = client.get_positions("VA000000".into());
+        assert!(response.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_get_positions_async() {
+        let _m = mock("GET", "/v1/accounts/VA000000/positions")
+            .with_status(200)
+            .with_body(include_str!("test_requests/get_positions.json"))
+            .create();
+
+        let client = TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
 
-        get_positions(&config, "VA000000".into()).unwrap();
-        let response = get_positions(&config, "VA000000".into());
+        let response = client.get_positions("VA000000".into()).await;
         assert!(response.is_ok());
     }
 }
@@ -1,11 +1,12 @@
 #![allow(non_camel_case_types)]
 
 use chrono::{DateTime, Utc};
-use eyre::Result;
 use optimistic_derives::*;
 use serde::{Deserialize, Serialize};
 
-use crate::{build_request_get, AccountStatus, AccountType, Classification, TradierConfig};
+#[cfg(feature = "blocking")]
+use crate::blocking;
+use crate::{AccountStatus, AccountType, Classification, Result, TradierClient};
 
 #[optimistic_no_c]
 pub struct Account {
@@ -66,20 +67,34 @@ impl From<ProfileEnum> for UserProfile {
     }
 }
 
-pub fn get_user_profile(config: &TradierConfig) -> Result<UserProfile> {
-    let response: ProfileEnum = build_request_get(config, "user/profile", None::<()>, None::<()>)
-        .send()?
-        .json()?;
+#[cfg(feature = "blocking")]
+impl blocking::TradierClient {
+    pub fn get_user_profile(&self) -> Result<UserProfile> {
+        let request = self.build_request_get("user/profile", None::<()>, None::<()>);
+        let response: ProfileEnum = self.send(request)?;
 
-    Ok(response.into())
+        Ok(response.into())
+    }
+}
+
+impl TradierClient {
+    pub async fn get_user_profile(&self) -> Result<UserProfile> {
+        let request = self.build_request_get("user/profile", None::<()>, None::<()>);
+        let response: ProfileEnum = self.send(request).await?;
+
+        Ok(response.into())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use mockito::mock;
 
-    use crate::{account::get_user_profile::get_user_profile, TradierConfig};
+    #[cfg(feature = "blocking")]
+    use crate::blocking;
+    use crate::{TradierClient, TradierConfig};
 
+    #[cfg(feature = "blocking")]
     #[test]
     fn test_get_user_profile() {
         let _m = mock("GET", "/v1/user/profile")
@@ -87,15 +102,13 @@ mod tests {
             .with_body(include_str!("test_requests/get_user_profile.json"))
             .create();
 
-        let config = TradierConfig {
-            token: "xxx".into(),
-            endpoint: mockito::server_url(),
-        };
+        let client = blocking::TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
 
-        let response = get_user_profile(&config);
+        let response = client.get_user_profile();
         assert!(response.is_ok());
     }
 
+    #[cfg(feature = "blocking")]
     #[test]
     fn test_get_user_profile_single() {
         let _m = mock("GET", "/v1/user/profile")
@@ -103,12 +116,22 @@ mod tests {
             .with_body(include_str!("test_requests/get_user_profile_single.json"))
             .create();
 
-        let config = TradierConfig {
-            token: "xxx".into(),
-            endpoint: mockito::server_url(),
-        };
+        let client = blocking::TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        let response = client.get_user_profile();
+        assert!(response.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_get_user_profile_async() {
+        let _m = mock("GET", "/v1/user/profile")
+            .with_status(200)
+            .with_body(include_str!("test_requests/get_user_profile.json"))
+            .create();
+
+        let client = TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
 
-        let response = get_user_profile(&config);
+        let response = client.get_user_profile().await;
         assert!(response.is_ok());
     }
 }
@@ -7,3 +7,9 @@ pub mod get_positions;
 pub mod get_user_profile;
 
 pub mod get_orders;
+
+pub mod get_order;
+
+pub mod get_gainloss;
+
+pub mod create_events_session;
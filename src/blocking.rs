@@ -0,0 +1,182 @@
+//! Blocking counterpart of [`TradierClient`](crate::TradierClient), for
+//! scripts that don't want to run an async runtime.
+
+use std::sync::Arc;
+
+use reqwest::blocking::RequestBuilder;
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+    endpoint, error::decode, market_data::get_etb::EtbCache, oauth::OAuth, Result,
+    TradierClientBuilder, TradierConfig,
+};
+
+#[derive(Debug, Clone)]
+pub struct TradierClient {
+    client: reqwest::blocking::Client,
+    /// `client` without the overall timeout, which would cut streams off.
+    #[cfg(feature = "streaming")]
+    stream_client: reqwest::blocking::Client,
+    config: TradierConfig,
+    check_short_sales: bool,
+    etb_cache: Arc<EtbCache>,
+    oauth: Option<OAuth>,
+}
+
+impl TradierClient {
+    pub fn new(config: TradierConfig) -> Self {
+        TradierClientBuilder::new(config)
+            .build_blocking()
+            .expect("failed to build the HTTP client")
+    }
+
+    /// Returns the shared builder; finish it with
+    /// [`build_blocking`](TradierClientBuilder::build_blocking).
+    pub fn builder(config: TradierConfig) -> TradierClientBuilder {
+        TradierClientBuilder::new(config)
+    }
+
+    pub(crate) fn from_parts(
+        client: reqwest::blocking::Client,
+        #[cfg(feature = "streaming")] stream_client: reqwest::blocking::Client,
+        config: TradierConfig,
+        check_short_sales: bool,
+        oauth: Option<OAuth>,
+    ) -> Self {
+        TradierClient {
+            client,
+            #[cfg(feature = "streaming")]
+            stream_client,
+            config,
+            check_short_sales,
+            etb_cache: Arc::default(),
+            oauth,
+        }
+    }
+
+    pub fn config(&self) -> &TradierConfig {
+        &self.config
+    }
+
+    pub(crate) fn checks_short_sales(&self) -> bool {
+        self.check_short_sales
+    }
+
+    /// Shared between clones, so the easy-to-borrow list is fetched once per day.
+    pub(crate) fn etb_cache(&self) -> &EtbCache {
+        &self.etb_cache
+    }
+
+    pub(crate) fn oauth(&self) -> Option<&OAuth> {
+        self.oauth.as_ref()
+    }
+
+    /// With OAuth the bearer token is only added by `send`, once it's known
+    /// to be fresh.
+    fn authorize(&self, request: RequestBuilder) -> RequestBuilder {
+        let request = request.header("Accept", "application/json");
+        match self.oauth {
+            Some(_) => request,
+            None => {
+                let token: &str = &self.config.token;
+                request.header("Authorization", format!("Bearer {}", token))
+            }
+        }
+    }
+
+    pub(crate) fn build_request_get(
+        &self,
+        path: &str,
+        _body: Option<impl Serialize>,
+        query: Option<impl Serialize>,
+    ) -> RequestBuilder {
+        let mut request = self.authorize(self.client.get(endpoint(&self.config, path)));
+        if let Some(q) = query {
+            request = request.query(&q);
+        }
+        request
+    }
+
+    pub(crate) fn build_request_post(
+        &self,
+        path: &str,
+        body: Option<impl Serialize>,
+        _query: Option<impl Serialize>,
+    ) -> RequestBuilder {
+        let mut request = self.authorize(self.client.post(endpoint(&self.config, path)));
+        if let Some(b) = body {
+            request = request.form(&b);
+        }
+        request
+    }
+
+    pub(crate) fn build_request_put(
+        &self,
+        path: &str,
+        body: Option<impl Serialize>,
+        _query: Option<impl Serialize>,
+    ) -> RequestBuilder {
+        let mut request = self.authorize(self.client.put(endpoint(&self.config, path)));
+        if let Some(b) = body {
+            request = request.form(&b);
+        }
+        request
+    }
+
+    #[cfg(feature = "streaming")]
+    /// POSTs a form to an absolute URL, such as the one a streaming session
+    /// hands out, rather than to a path under the configured endpoint. The
+    /// response body may stay open indefinitely.
+    pub(crate) fn build_request_stream(
+        &self,
+        url: &str,
+        body: Option<impl Serialize>,
+    ) -> RequestBuilder {
+        let mut request = self.authorize(self.stream_client.post(url));
+        if let Some(b) = body {
+            request = request.form(&b);
+        }
+        request
+    }
+
+    pub(crate) fn build_request_del(&self, path: &str) -> RequestBuilder {
+        self.authorize(self.client.delete(endpoint(&self.config, path)))
+    }
+
+    /// For the OAuth token endpoints, which authenticate the app rather than a user.
+    pub(crate) fn build_request_token(
+        &self,
+        oauth: &OAuth,
+        path: &str,
+        body: impl Serialize,
+    ) -> RequestBuilder {
+        self.client
+            .post(endpoint(&self.config, path))
+            .header("Accept", "application/json")
+            .basic_auth(&oauth.client_id, Some(&oauth.client_secret))
+            .form(&body)
+    }
+
+    /// Adds a fresh OAuth bearer token when the client uses OAuth; requests
+    /// built with a static token already carry it.
+    pub(crate) fn bearer_request(&self, request: RequestBuilder) -> Result<RequestBuilder> {
+        match &self.oauth {
+            Some(oauth) => Ok(request.bearer_auth(self.access_token(oauth)?)),
+            None => Ok(request),
+        }
+    }
+
+    pub(crate) fn send<T: DeserializeOwned>(&self, request: RequestBuilder) -> Result<T> {
+        let request = self.bearer_request(request)?;
+        self.execute(request)
+    }
+
+    /// Sends `request` as is and decodes the response.
+    pub(crate) fn execute<T: DeserializeOwned>(&self, request: RequestBuilder) -> Result<T> {
+        let response = request.send()?;
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = response.text()?;
+        decode(status, &headers, body)
+    }
+}
@@ -0,0 +1,336 @@
+use std::sync::Arc;
+
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue},
+    Proxy, RequestBuilder,
+};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+    endpoint, error::decode, market_data::get_etb::EtbCache, oauth::OAuth, Result, TradierConfig,
+};
+
+/// Asynchronous Tradier client built on a non-blocking `reqwest::Client`.
+///
+/// Endpoint methods live next to the response types they return, in the
+/// `account`, `market_data` and `trading` modules.
+#[derive(Debug, Clone)]
+pub struct TradierClient {
+    client: reqwest::Client,
+    config: TradierConfig,
+    check_short_sales: bool,
+    etb_cache: Arc<EtbCache>,
+    oauth: Option<OAuth>,
+}
+
+impl TradierClient {
+    pub fn new(config: TradierConfig) -> Self {
+        TradierClient {
+            client: reqwest::Client::new(),
+            config,
+            check_short_sales: false,
+            etb_cache: Arc::default(),
+            oauth: None,
+        }
+    }
+
+    pub fn builder(config: TradierConfig) -> TradierClientBuilder {
+        TradierClientBuilder::new(config)
+    }
+
+    pub fn config(&self) -> &TradierConfig {
+        &self.config
+    }
+
+    pub(crate) fn checks_short_sales(&self) -> bool {
+        self.check_short_sales
+    }
+
+    /// Shared between clones, so the easy-to-borrow list is fetched once per day.
+    pub(crate) fn etb_cache(&self) -> &EtbCache {
+        &self.etb_cache
+    }
+
+    pub(crate) fn oauth(&self) -> Option<&OAuth> {
+        self.oauth.as_ref()
+    }
+
+    /// With OAuth the bearer token is only added by `send`, once it's known
+    /// to be fresh.
+    fn authorize(&self, request: RequestBuilder) -> RequestBuilder {
+        let request = request.header("Accept", "application/json");
+        match self.oauth {
+            Some(_) => request,
+            None => {
+                let token: &str = &self.config.token;
+                request.header("Authorization", format!("Bearer {}", token))
+            }
+        }
+    }
+
+    pub(crate) fn build_request_get(
+        &self,
+        path: &str,
+        _body: Option<impl Serialize>,
+        query: Option<impl Serialize>,
+    ) -> RequestBuilder {
+        let mut request = self.authorize(self.client.get(endpoint(&self.config, path)));
+        if let Some(q) = query {
+            request = request.query(&q);
+        }
+        request
+    }
+
+    pub(crate) fn build_request_post(
+        &self,
+        path: &str,
+        body: Option<impl Serialize>,
+        _query: Option<impl Serialize>,
+    ) -> RequestBuilder {
+        let mut request = self.authorize(self.client.post(endpoint(&self.config, path)));
+        if let Some(b) = body {
+            request = request.form(&b);
+        }
+        request
+    }
+
+    pub(crate) fn build_request_put(
+        &self,
+        path: &str,
+        body: Option<impl Serialize>,
+        _query: Option<impl Serialize>,
+    ) -> RequestBuilder {
+        let mut request = self.authorize(self.client.put(endpoint(&self.config, path)));
+        if let Some(b) = body {
+            request = request.form(&b);
+        }
+        request
+    }
+
+    pub(crate) fn build_request_del(&self, path: &str) -> RequestBuilder {
+        self.authorize(self.client.delete(endpoint(&self.config, path)))
+    }
+
+    /// For the OAuth token endpoints, which authenticate the app rather than a user.
+    pub(crate) fn build_request_token(
+        &self,
+        oauth: &OAuth,
+        path: &str,
+        body: impl Serialize,
+    ) -> RequestBuilder {
+        self.client
+            .post(endpoint(&self.config, path))
+            .header("Accept", "application/json")
+            .basic_auth(&oauth.client_id, Some(&oauth.client_secret))
+            .form(&body)
+    }
+
+    /// Adds a fresh OAuth bearer token when the client uses OAuth; requests
+    /// built with a static token already carry it.
+    pub(crate) async fn bearer_request(&self, request: RequestBuilder) -> Result<RequestBuilder> {
+        match &self.oauth {
+            Some(oauth) => Ok(request.bearer_auth(self.access_token(oauth).await?)),
+            None => Ok(request),
+        }
+    }
+
+    pub(crate) async fn send<T: DeserializeOwned>(&self, request: RequestBuilder) -> Result<T> {
+        let request = self.bearer_request(request).await?;
+        self.execute(request).await
+    }
+
+    /// Sends `request` as is and decodes the response.
+    pub(crate) async fn execute<T: DeserializeOwned>(&self, request: RequestBuilder) -> Result<T> {
+        let response = request.send().await?;
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = response.text().await?;
+        decode(status, &headers, body)
+    }
+}
+
+/// Configures the underlying `reqwest` client for either the async
+/// [`TradierClient`] or, with the `blocking` feature, the
+/// [`blocking::TradierClient`](crate::blocking::TradierClient).
+#[derive(Debug)]
+pub struct TradierClientBuilder {
+    config: TradierConfig,
+    headers: HeaderMap,
+    timeout: Option<std::time::Duration>,
+    connect_timeout: Option<std::time::Duration>,
+    user_agent: Option<String>,
+    proxy: Option<Proxy>,
+    pool_max_idle_per_host: Option<usize>,
+    check_short_sales: bool,
+    oauth: Option<OAuth>,
+}
+
+impl TradierClientBuilder {
+    pub fn new(config: TradierConfig) -> Self {
+        TradierClientBuilder {
+            config,
+            headers: HeaderMap::new(),
+            timeout: None,
+            connect_timeout: None,
+            user_agent: None,
+            proxy: None,
+            pool_max_idle_per_host: None,
+            check_short_sales: false,
+            oauth: None,
+        }
+    }
+
+    /// Overrides the endpoint from the config, e.g. to point at a mock server.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.config.endpoint = base_url.into();
+        self
+    }
+
+    /// Bounds each whole request, body included. Market streams aren't
+    /// subject to it; they use their own idle timeout instead.
+    pub fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn connect_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    pub fn default_header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.headers.insert(name, value);
+        self
+    }
+
+    pub fn default_headers(mut self, headers: HeaderMap) -> Self {
+        self.headers.extend(headers);
+        self
+    }
+
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    pub fn proxy(mut self, proxy: Proxy) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    /// Reject `sell_short` orders on symbols missing from the easy-to-borrow
+    /// list before sending them. The list is fetched once per trading day.
+    pub fn check_short_sales(mut self, check: bool) -> Self {
+        self.check_short_sales = check;
+        self
+    }
+
+    /// Authenticate with OAuth tokens from `oauth`'s store instead of the
+    /// config's static token, refreshing them as they expire.
+    pub fn oauth(mut self, oauth: OAuth) -> Self {
+        self.oauth = Some(oauth);
+        self
+    }
+
+    pub fn build(self) -> Result<TradierClient> {
+        let mut builder = reqwest::Client::builder().default_headers(self.headers);
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+        if let Some(user_agent) = self.user_agent {
+            builder = builder.user_agent(user_agent);
+        }
+        if let Some(proxy) = self.proxy {
+            builder = builder.proxy(proxy);
+        }
+        if let Some(max) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max);
+        }
+
+        Ok(TradierClient {
+            client: builder.build()?,
+            config: self.config,
+            check_short_sales: self.check_short_sales,
+            etb_cache: Arc::default(),
+            oauth: self.oauth,
+        })
+    }
+
+    #[cfg(feature = "blocking")]
+    pub fn build_blocking(self) -> Result<crate::blocking::TradierClient> {
+        let mut builder = self.blocking_client();
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+
+        Ok(crate::blocking::TradierClient::from_parts(
+            builder.build()?,
+            #[cfg(feature = "streaming")]
+            self.blocking_client().timeout(None).build()?,
+            self.config,
+            self.check_short_sales,
+            self.oauth,
+        ))
+    }
+
+    /// Every setting but the overall timeout, which streams can't have.
+    #[cfg(feature = "blocking")]
+    fn blocking_client(&self) -> reqwest::blocking::ClientBuilder {
+        let mut builder =
+            reqwest::blocking::Client::builder().default_headers(self.headers.clone());
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+        if let Some(user_agent) = &self.user_agent {
+            builder = builder.user_agent(user_agent);
+        }
+        if let Some(proxy) = &self.proxy {
+            builder = builder.proxy(proxy.clone());
+        }
+        if let Some(max) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max);
+        }
+        builder
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::mock;
+    use reqwest::header::{HeaderName, HeaderValue};
+
+    use crate::{TradierClient, TradierConfig, SANDBOX_ENDPOINT};
+
+    #[tokio::test]
+    async fn test_builder_headers_and_base_url() {
+        let _m = mock("GET", "/v1/accounts/VA000000/balances")
+            .match_header("x-desk", "options")
+            .match_header("user-agent", "tradier-test")
+            .with_status(200)
+            .with_body(include_str!("account/test_requests/get_balances.json"))
+            .create();
+
+        let config = TradierConfig::new("xxx", SANDBOX_ENDPOINT);
+        let client = TradierClient::builder(config)
+            .base_url(mockito::server_url())
+            .timeout(std::time::Duration::from_secs(5))
+            .user_agent("tradier-test")
+            .default_header(
+                HeaderName::from_static("x-desk"),
+                HeaderValue::from_static("options"),
+            )
+            .build()
+            .unwrap();
+
+        let response = client.get_balances("VA000000".into()).await;
+        assert!(response.is_ok());
+    }
+}
@@ -0,0 +1,257 @@
+use std::path::Path;
+
+use ::config::{Config, ConfigError, File};
+
+use crate::{Result, TradierConfig};
+
+pub const LIVE_ENDPOINT: &str = "https://api.tradier.com";
+pub const SANDBOX_ENDPOINT: &str = "https://sandbox.tradier.com";
+
+const CONFIG_FILE: &str = "Config.toml";
+const SANDBOX_PROFILES: [&str; 2] = ["sandbox", "paper"];
+
+impl TradierConfig {
+    pub fn new(token: impl Into<String>, endpoint: impl Into<String>) -> Self {
+        TradierConfig {
+            token: token.into(),
+            endpoint: endpoint.into(),
+            account_id: None,
+        }
+    }
+
+    /// Loads `Config.toml` from the working directory (if present), using the
+    /// profile named by `TRADIER_PROFILE` (if set).
+    ///
+    /// Values are layered as: top-level keys, then the profile's section
+    /// (e.g. `[live]`), then `TRADIER_TOKEN`, `TRADIER_ENDPOINT` and
+    /// `TRADIER_ACCOUNT_ID`. The `live`, `sandbox` and `paper` profiles
+    /// default to their endpoint ahead of a top-level `endpoint`.
+    ///
+    /// Loading fails if a sandbox token ends up pointed at the live endpoint.
+    /// Tokens don't say where they were issued, so only placeholder tokens
+    /// like `"xxx"` and exact matches of the `[sandbox]` or `[paper]` token
+    /// are caught; any other sandbox token passes.
+    pub fn load() -> Result<Self> {
+        let profile = std::env::var("TRADIER_PROFILE").ok();
+        load_with(Path::new(CONFIG_FILE), false, profile.as_deref(), |key| {
+            std::env::var(key).ok()
+        })
+    }
+
+    pub fn load_profile(profile: &str) -> Result<Self> {
+        load_with(Path::new(CONFIG_FILE), false, Some(profile), |key| {
+            std::env::var(key).ok()
+        })
+    }
+
+    pub fn load_from(path: impl AsRef<Path>, profile: Option<&str>) -> Result<Self> {
+        load_with(path.as_ref(), true, profile, |key| std::env::var(key).ok())
+    }
+
+    /// `account_id` when given, otherwise the configured default account,
+    /// for passing to account and trading calls.
+    pub fn account(&self, account_id: Option<String>) -> Result<String> {
+        account_id
+            .or_else(|| self.account_id.clone())
+            .ok_or_else(|| ConfigError::NotFound("account_id".into()).into())
+    }
+
+    pub fn is_live(&self) -> bool {
+        self.endpoint.trim_end_matches('/') == LIVE_ENDPOINT
+    }
+}
+
+/// The endpoint implied by a well-known profile name.
+fn default_endpoint(profile: &str) -> Option<&'static str> {
+    match profile {
+        "live" => Some(LIVE_ENDPOINT),
+        _ if SANDBOX_PROFILES.contains(&profile) => Some(SANDBOX_ENDPOINT),
+        _ => None,
+    }
+}
+
+/// Tradier tokens don't encode which environment issued them, so a token is
+/// treated as a sandbox token if it's a placeholder like the shipped `"xxx"`
+/// or if it's the token configured for the `[sandbox]` or `[paper]` profile.
+fn is_sandbox_token(file: &Config, token: &str) -> bool {
+    let mut chars = token.chars();
+    let placeholder = match chars.next() {
+        Some(first) => chars.all(|c| c == first),
+        None => true,
+    };
+
+    placeholder
+        || SANDBOX_PROFILES.iter().any(|profile| {
+            file.get_str(&format!("{}.token", profile))
+                .map(|sandbox_token| sandbox_token == token)
+                .unwrap_or(false)
+        })
+}
+
+fn load_with(
+    path: &Path,
+    required: bool,
+    profile: Option<&str>,
+    env: impl Fn(&str) -> Option<String>,
+) -> Result<TradierConfig> {
+    // A missing file or profile section just leaves those layers empty, so
+    // a profile can be configured entirely through the environment.
+    let mut file = Config::default();
+    file.merge(File::from(path).required(required))?;
+
+    let from_env = |key: &str| env(&format!("TRADIER_{}", key.to_uppercase()));
+    let from_profile =
+        |key: &str| profile.and_then(|p| file.get_str(&format!("{}.{}", p, key)).ok());
+    let lookup = |key: &str| {
+        from_env(key)
+            .or_else(|| from_profile(key))
+            .or_else(|| file.get_str(key).ok())
+    };
+
+    let token = lookup("token").ok_or_else(|| ConfigError::NotFound("token".into()))?;
+    // A `[live]` section shouldn't inherit a top-level sandbox endpoint.
+    let endpoint = from_env("endpoint")
+        .or_else(|| from_profile("endpoint"))
+        .or_else(|| profile.and_then(default_endpoint).map(str::to_string))
+        .or_else(|| file.get_str("endpoint").ok())
+        .ok_or_else(|| ConfigError::NotFound("endpoint".into()))?;
+    let config = TradierConfig {
+        token,
+        endpoint,
+        account_id: lookup("account_id"),
+    };
+
+    if config.is_live() && is_sandbox_token(&file, &config.token) {
+        return Err(ConfigError::Message(format!(
+            "refusing to use a sandbox token against the live endpoint {}",
+            config.endpoint
+        ))
+        .into());
+    }
+
+    Ok(config)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, path::PathBuf};
+
+    use crate::{
+        config::{load_with, LIVE_ENDPOINT, SANDBOX_ENDPOINT},
+        TradierError,
+    };
+
+    fn write_config(name: &str, contents: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("tradier-{}.toml", name));
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    const CONFIG: &str = r#"
+token = "root-token"
+endpoint = "https://sandbox.tradier.com"
+account_id = "VA000000"
+
+[sandbox]
+token = "sandbox-token"
+
+[live]
+token = "live-token"
+account_id = "6YA00000"
+"#;
+
+    #[test]
+    fn test_load_layers() {
+        let path = write_config("layers", CONFIG);
+        let no_env = |_: &str| None;
+
+        let root = load_with(&path, true, None, no_env).unwrap();
+        assert_eq!(root.token, "root-token");
+        assert_eq!(root.account_id.as_deref(), Some("VA000000"));
+
+        let sandbox = load_with(&path, true, Some("sandbox"), no_env).unwrap();
+        assert_eq!(sandbox.token, "sandbox-token");
+        assert_eq!(sandbox.endpoint, SANDBOX_ENDPOINT);
+        assert_eq!(sandbox.account_id.as_deref(), Some("VA000000"));
+
+        let env: HashMap<&str, &str> = vec![
+            ("TRADIER_ENDPOINT", LIVE_ENDPOINT),
+            ("TRADIER_TOKEN", "env-token"),
+        ]
+        .into_iter()
+        .collect();
+        let live = load_with(&path, true, Some("live"), |key| {
+            env.get(key).map(|v| v.to_string())
+        })
+        .unwrap();
+        assert_eq!(live.token, "env-token");
+        assert_eq!(live.endpoint, LIVE_ENDPOINT);
+        assert_eq!(live.account_id.as_deref(), Some("6YA00000"));
+
+        // A profile without a section falls back to the top-level keys.
+        let missing = load_with(&path, true, Some("missing"), no_env).unwrap();
+        assert_eq!(missing.token, "root-token");
+        assert_eq!(
+            missing.account(Some("6YA11111".into())).unwrap(),
+            "6YA11111"
+        );
+        assert_eq!(missing.account(None).unwrap(), "VA000000");
+
+        // The live profile's own endpoint beats the top-level sandbox one.
+        let live_section = write_config(
+            "live-section",
+            "endpoint = \"https://sandbox.tradier.com\"\n[live]\ntoken = \"live-token\"\n",
+        );
+        let live = load_with(&live_section, true, Some("live"), no_env).unwrap();
+        assert_eq!(live.endpoint, LIVE_ENDPOINT);
+        assert!(live.is_live());
+
+        let no_token = write_config("no-token", "[live]\n");
+        assert!(matches!(
+            load_with(&no_token, true, Some("live"), no_env),
+            Err(TradierError::Config(_))
+        ));
+    }
+
+    #[test]
+    fn test_load_profile_from_env_only() {
+        let path = std::env::temp_dir().join("tradier-does-not-exist.toml");
+        let env = |key: &str| match key {
+            "TRADIER_TOKEN" => Some("env-token".to_string()),
+            "TRADIER_ENDPOINT" => Some(LIVE_ENDPOINT.to_string()),
+            _ => None,
+        };
+
+        let live = load_with(&path, false, Some("live"), env).unwrap();
+        assert_eq!(live.token, "env-token");
+        assert_eq!(live.endpoint, LIVE_ENDPOINT);
+        assert!(live.account(None).is_err());
+
+        let paper = load_with(&path, false, Some("paper"), |key| match key {
+            "TRADIER_TOKEN" => Some("env-token".to_string()),
+            _ => None,
+        })
+        .unwrap();
+        assert_eq!(paper.endpoint, SANDBOX_ENDPOINT);
+    }
+
+    #[test]
+    fn test_load_rejects_sandbox_token_on_live() {
+        let path = write_config("live", CONFIG);
+        let live_with_sandbox_token = |key: &str| match key {
+            "TRADIER_ENDPOINT" => Some(LIVE_ENDPOINT.to_string()),
+            "TRADIER_TOKEN" => Some("sandbox-token".to_string()),
+            _ => None,
+        };
+        assert!(matches!(
+            load_with(&path, true, None, live_with_sandbox_token),
+            Err(TradierError::Config(_))
+        ));
+
+        let placeholder = write_config("placeholder", "token = \"xxx\"\n[live]\n");
+        assert!(matches!(
+            load_with(&placeholder, true, Some("live"), |_| None),
+            Err(TradierError::Config(_))
+        ));
+    }
+}
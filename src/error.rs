@@ -0,0 +1,172 @@
+use chrono::{DateTime, TimeZone, Utc};
+use reqwest::{header::HeaderMap, StatusCode};
+use serde::{de::DeserializeOwned, Deserialize};
+
+pub type Result<T> = std::result::Result<T, TradierError>;
+
+#[derive(Debug, thiserror::Error)]
+pub enum TradierError {
+    /// 401: the token is missing, expired or not allowed to use the endpoint.
+    #[error("unauthorized: {body}")]
+    Unauthorized { body: String },
+    /// 429: `retry_at` comes from Tradier's `X-Ratelimit-Expiry` header when present.
+    #[error("rate limited until {retry_at:?}: {body}")]
+    RateLimited {
+        retry_at: Option<DateTime<Utc>>,
+        body: String,
+    },
+    /// 400: the request was rejected, `messages` holds Tradier's `errors.error` entries.
+    #[error("validation failed: {messages:?}")]
+    Validation { messages: Vec<String>, body: String },
+    /// Any other non-success status.
+    #[error("unexpected status {status}: {body}")]
+    Status { status: StatusCode, body: String },
+    /// The order was rejected locally, before anything was sent to Tradier.
+    #[error("invalid order: {0}")]
+    InvalidOrder(String),
+    /// OAuth isn't set up on the client, or there's no usable token to send.
+    #[error("oauth error: {0}")]
+    OAuth(String),
+    #[error("configuration error: {0}")]
+    Config(#[from] ::config::ConfigError),
+    #[error("transport error: {0}")]
+    Transport(#[from] reqwest::Error),
+    /// The response had a success status but didn't match the expected shape.
+    #[error("failed to decode response: {source}; body: {body}")]
+    Decode {
+        #[source]
+        source: serde_json::Error,
+        body: String,
+    },
+    /// The stream connection failed or broke.
+    #[cfg(feature = "streaming")]
+    #[error("websocket error: {0}")]
+    WebSocket(#[source] Box<tungstenite::Error>),
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+#[cfg(feature = "streaming")]
+impl From<tungstenite::Error> for TradierError {
+    fn from(error: tungstenite::Error) -> Self {
+        TradierError::WebSocket(Box::new(error))
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ErrorMessages {
+    Many(Vec<String>),
+    One(String),
+}
+
+#[derive(Deserialize)]
+struct Errors {
+    error: ErrorMessages,
+}
+
+#[derive(Deserialize)]
+struct ErrorsRoot {
+    errors: Errors,
+}
+
+fn messages(body: &str) -> Vec<String> {
+    match serde_json::from_str::<ErrorsRoot>(body) {
+        Ok(root) => match root.errors.error {
+            ErrorMessages::Many(messages) => messages,
+            ErrorMessages::One(message) => vec![message],
+        },
+        Err(_) if body.trim().is_empty() => vec![],
+        Err(_) => vec![body.trim().to_string()],
+    }
+}
+
+fn retry_at(headers: &HeaderMap) -> Option<DateTime<Utc>> {
+    let expiry = headers.get("X-Ratelimit-Expiry")?.to_str().ok()?;
+    Utc.timestamp_millis_opt(expiry.parse().ok()?).single()
+}
+
+/// Turns a raw response into either the expected type or the matching
+/// [`TradierError`]. Shared by the async and blocking clients.
+pub(crate) fn decode<T: DeserializeOwned>(
+    status: StatusCode,
+    headers: &HeaderMap,
+    body: String,
+) -> Result<T> {
+    match status {
+        s if s.is_success() => {
+            serde_json::from_str(&body).map_err(|source| TradierError::Decode { source, body })
+        }
+        status => Err(status_error(status, headers, body)),
+    }
+}
+
+/// The [`TradierError`] for a response with a non-success status.
+pub(crate) fn status_error(status: StatusCode, headers: &HeaderMap, body: String) -> TradierError {
+    match status {
+        StatusCode::UNAUTHORIZED => TradierError::Unauthorized { body },
+        StatusCode::TOO_MANY_REQUESTS => TradierError::RateLimited {
+            retry_at: retry_at(headers),
+            body,
+        },
+        StatusCode::BAD_REQUEST => TradierError::Validation {
+            messages: messages(&body),
+            body,
+        },
+        status => TradierError::Status { status, body },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::{
+        header::{HeaderMap, HeaderValue},
+        StatusCode,
+    };
+
+    use crate::{error::decode, TradierError};
+
+    #[test]
+    fn test_decode_validation_messages() {
+        let headers = HeaderMap::new();
+        let many = r#"{"errors":{"error":["Backend rejected","Invalid price"]}}"#;
+        match decode::<()>(StatusCode::BAD_REQUEST, &headers, many.into()) {
+            Err(TradierError::Validation { messages, .. }) => {
+                assert_eq!(messages, vec!["Backend rejected", "Invalid price"])
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+
+        let one = r#"{"errors":{"error":"Invalid Parameter"}}"#;
+        match decode::<()>(StatusCode::BAD_REQUEST, &headers, one.into()) {
+            Err(TradierError::Validation { messages, .. }) => {
+                assert_eq!(messages, vec!["Invalid Parameter"])
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_decode_rate_limited() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "X-Ratelimit-Expiry",
+            HeaderValue::from_static("1629000000000"),
+        );
+        match decode::<()>(StatusCode::TOO_MANY_REQUESTS, &headers, "".into()) {
+            Err(TradierError::RateLimited { retry_at, .. }) => {
+                assert_eq!(retry_at.unwrap().timestamp(), 1629000000)
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_decode_failure_keeps_body() {
+        let headers = HeaderMap::new();
+        match decode::<Vec<u64>>(StatusCode::OK, &headers, "not json".into()) {
+            Err(TradierError::Decode { body, .. }) => assert_eq!(body, "not json"),
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+}
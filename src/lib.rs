@@ -1,19 +1,18 @@
 #![allow(non_camel_case_types)]
 
-use once_cell::sync::Lazy;
-use reqwest::blocking::RequestBuilder;
 use serde::{Deserialize, Serialize};
 
 use optimistic_derives::*;
 
 const VERSION: &str = "v1";
 
-static CLIENT: Lazy<reqwest::blocking::Client> = Lazy::new(reqwest::blocking::Client::new);
-
 #[optimistic_no_c]
 pub struct TradierConfig {
     pub token: String,
     pub endpoint: String,
+    /// Default account for scripts that only trade one account, see
+    /// [`TradierConfig::account`].
+    pub account_id: Option<String>,
 }
 
 #[optimistic]
@@ -39,6 +38,9 @@ pub enum Class {
     option,
     multileg,
     combo,
+    oto,
+    oco,
+    otoco,
 }
 
 impl std::fmt::Display for Class {
@@ -59,6 +61,12 @@ pub enum Side {
     sell_to_close,
 }
 
+impl std::fmt::Display for Side {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
 #[optimistic]
 pub enum Duration {
     day,
@@ -67,6 +75,12 @@ pub enum Duration {
     post,
 }
 
+impl std::fmt::Display for Duration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
 #[optimistic]
 pub enum OrderStatus {
     open,
@@ -105,51 +119,30 @@ pub enum AccountStatus {
     closed,
 }
 
+/// Beta endpoints such as `beta/markets/fundamentals/company` aren't versioned.
 fn endpoint(config: &TradierConfig, path: &str) -> String {
     let endpoint: &str = &config.endpoint;
-    format!("{}/{}/{}", endpoint, VERSION, path)
-}
-
-fn build_request_get(
-    config: &TradierConfig,
-    path: &str,
-    _body: Option<impl Serialize>,
-    query: Option<impl Serialize>,
-) -> RequestBuilder {
-    let token: &str = &config.token;
-    let mut request = CLIENT.get(endpoint(config, path));
-    request = request.header("Accept", "application/json");
-    request = request.header("Authorization", format!("Bearer {}", token));
-    if let Some(q) = query {
-        request = request.query(&q);
+    if path.starts_with("beta/") {
+        format!("{}/{}", endpoint, path)
+    } else {
+        format!("{}/{}/{}", endpoint, VERSION, path)
     }
-    request
 }
 
-fn build_request_post(
-    config: &TradierConfig,
-    path: &str,
-    body: Option<impl Serialize>,
-    _query: Option<impl Serialize>,
-) -> RequestBuilder {
-    let token: &str = &config.token;
-    let mut request = CLIENT.post(endpoint(config, path));
-    request = request.header("Accept", "application/json");
-    request = request.header("Authorization", format!("Bearer {}", token));
-    if let Some(b) = body {
-        request = request.form(&b);
-    }
-    request
-}
+#[cfg(feature = "blocking")]
+pub mod blocking;
+mod client;
+mod config;
+mod error;
 
-fn build_request_del(config: &TradierConfig, path: &str) -> RequestBuilder {
-    let token: &str = &config.token;
-    let mut request = CLIENT.delete(endpoint(config, path));
-    request = request.header("Accept", "application/json");
-    request = request.header("Authorization", format!("Bearer {}", token));
-    request
-}
+pub use client::{TradierClient, TradierClientBuilder};
+pub use config::{LIVE_ENDPOINT, SANDBOX_ENDPOINT};
+pub use error::{Result, TradierError};
 
 pub mod account;
 pub mod market_data;
+pub mod oauth;
+#[cfg(feature = "streaming")]
+pub mod streaming;
 pub mod trading;
+pub mod watchlists;
@@ -0,0 +1,55 @@
+use optimistic_derives::*;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "blocking")]
+use crate::blocking;
+use crate::{Result, TradierClient};
+
+/// A streaming session. `sessionid` is only valid for a few minutes and
+/// must be sent with the subscription; `url` is the HTTP streaming endpoint.
+#[optimistic_no_c]
+pub struct StreamSession {
+    pub url: String,
+    pub sessionid: String,
+}
+
+#[optimistic_no_c]
+pub struct StreamSessionRoot {
+    pub stream: StreamSession,
+}
+
+#[cfg(feature = "blocking")]
+impl blocking::TradierClient {
+    pub fn create_market_events_session(&self) -> Result<StreamSessionRoot> {
+        let request = self.build_request_post("markets/events/session", None::<()>, None::<()>);
+        self.send(request)
+    }
+}
+
+impl TradierClient {
+    pub async fn create_market_events_session(&self) -> Result<StreamSessionRoot> {
+        let request = self.build_request_post("markets/events/session", None::<()>, None::<()>);
+        self.send(request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::mock;
+
+    use crate::{TradierClient, TradierConfig};
+
+    #[tokio::test]
+    async fn test_create_market_events_session() {
+        let _m = mock("POST", "/v1/markets/events/session")
+            .with_status(200)
+            .with_body(include_str!("test_requests/create_events_session.json"))
+            .create();
+
+        let client = TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        let session = client.create_market_events_session().await.unwrap().stream;
+        assert_eq!(session.sessionid, "c8638963-a6d4-4fb9-9bc6-e25fbd8c60c3");
+        assert_eq!(session.url, "https://stream.tradier.com/v1/markets/events");
+    }
+}
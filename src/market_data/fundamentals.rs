@@ -0,0 +1,727 @@
+//! Tradier's beta fundamentals endpoints. Each returns one entry per requested
+//! symbol, holding `results` whose `tables` are only partly filled in, so nearly
+//! every field is optional and tables that can be an object or a list are read
+//! as a list.
+
+use std::collections::BTreeMap;
+
+use chrono::NaiveDate;
+use optimistic_derives::*;
+use serde::{Deserialize, Deserializer, Serialize};
+
+#[cfg(feature = "blocking")]
+use crate::blocking;
+use crate::{Result, TradierClient};
+
+/// Accepts a missing value, `null`, a single object or a list.
+fn one_or_many<'de, D, T>(deserializer: D) -> std::result::Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany<T> {
+        One(T),
+        Many(Vec<T>),
+    }
+
+    Ok(match Option::<OneOrMany<T>>::deserialize(deserializer)? {
+        Some(OneOrMany::One(one)) => vec![one],
+        Some(OneOrMany::Many(many)) => many,
+        None => vec![],
+    })
+}
+
+/// Fundamentals for one requested symbol.
+#[optimistic_no_ceho]
+#[serde(bound(deserialize = "T: Deserialize<'de>"))]
+pub struct Fundamentals<T> {
+    pub request: String,
+    #[serde(alias = "type")]
+    pub request_type: String,
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub results: Vec<FundamentalsResult<T>>,
+}
+
+impl<T> Fundamentals<T> {
+    /// Tables of every result that has them.
+    pub fn tables(&self) -> impl Iterator<Item = &T> {
+        self.results
+            .iter()
+            .filter_map(|result| result.tables.as_ref())
+    }
+}
+
+/// `result_type` is e.g. `Company` or `Stock`; a symbol can have both.
+#[optimistic_no_ceho]
+pub struct FundamentalsResult<T> {
+    #[serde(alias = "type")]
+    pub result_type: String,
+    pub id: Option<String>,
+    pub tables: Option<T>,
+}
+
+#[optimistic_no_c]
+pub struct Address {
+    pub address_line1: Option<String>,
+    pub city: Option<String>,
+    pub country: Option<String>,
+    pub fax: Option<String>,
+    pub homepage: Option<String>,
+    pub phone: Option<String>,
+    pub postal_code: Option<String>,
+    pub province: Option<String>,
+}
+
+#[optimistic_no_ceho]
+pub struct CompanyProfile {
+    pub company_id: Option<String>,
+    pub average_employee_number: Option<f64>,
+    pub total_employee_number: Option<f64>,
+    pub contact_email: Option<String>,
+    pub headquarter: Option<Address>,
+}
+
+/// Morningstar classification codes and grades.
+#[optimistic_no_ceho]
+pub struct AssetClassification {
+    pub company_id: Option<String>,
+    pub financial_health_grade: Option<String>,
+    pub growth_grade: Option<String>,
+    pub profitability_grade: Option<String>,
+    pub morningstar_economy_sphere_code: Option<i64>,
+    pub morningstar_sector_code: Option<i64>,
+    pub morningstar_industry_group_code: Option<i64>,
+    pub morningstar_industry_code: Option<i64>,
+    pub stock_type: Option<i64>,
+    pub style_box: Option<i64>,
+    pub growth_score: Option<f64>,
+    pub value_score: Option<f64>,
+    pub size_score: Option<f64>,
+}
+
+#[optimistic_no_c]
+pub struct ShareClass {
+    pub share_class_id: Option<String>,
+    pub company_id: Option<String>,
+    pub symbol: Option<String>,
+    pub currency_id: Option<String>,
+    pub exchange_id: Option<String>,
+    pub ipo_date: Option<NaiveDate>,
+    pub is_primary_share: Option<bool>,
+    pub security_type: Option<String>,
+    pub share_class_status: Option<String>,
+}
+
+#[optimistic_no_ceho]
+pub struct ShareClassProfile {
+    pub share_class_id: Option<String>,
+    pub enterprise_value: Option<f64>,
+    pub market_cap: Option<f64>,
+    pub shares_outstanding: Option<f64>,
+}
+
+/// `beta/markets/fundamentals/company`
+#[optimistic_no_ceho]
+pub struct CompanyTables {
+    pub company_profile: Option<CompanyProfile>,
+    pub asset_classification: Option<AssetClassification>,
+    pub long_descriptions: Option<String>,
+    pub share_class: Option<ShareClass>,
+    pub share_class_profile: Option<ShareClassProfile>,
+}
+
+#[optimistic_no_ceho]
+pub struct OperationRatios {
+    pub as_of_date: Option<NaiveDate>,
+    pub fiscal_year_end: Option<i64>,
+    pub period: Option<String>,
+    pub report_type: Option<String>,
+    pub gross_margin: Option<f64>,
+    pub operation_margin: Option<f64>,
+    pub pretax_margin: Option<f64>,
+    pub net_margin: Option<f64>,
+    #[serde(alias = "r_o_a")]
+    pub roa: Option<f64>,
+    #[serde(alias = "r_o_e")]
+    pub roe: Option<f64>,
+    #[serde(alias = "r_o_i_c")]
+    pub roic: Option<f64>,
+    pub current_ratio: Option<f64>,
+    pub quick_ratio: Option<f64>,
+    pub total_debt_equity_ratio: Option<f64>,
+    pub long_term_debt_equity_ratio: Option<f64>,
+    pub assets_turnover: Option<f64>,
+    pub revenue_growth: Option<f64>,
+    pub net_income_growth: Option<f64>,
+}
+
+#[optimistic_no_ceho]
+pub struct ValuationRatios {
+    pub share_class_id: Option<String>,
+    pub as_of_date: Option<NaiveDate>,
+    #[serde(alias = "p_e_ratio")]
+    pub pe_ratio: Option<f64>,
+    #[serde(alias = "forward_p_e_ratio")]
+    pub forward_pe_ratio: Option<f64>,
+    #[serde(alias = "p_b_ratio")]
+    pub pb_ratio: Option<f64>,
+    #[serde(alias = "p_s_ratio")]
+    pub ps_ratio: Option<f64>,
+    #[serde(alias = "p_c_f_ratio")]
+    pub pcf_ratio: Option<f64>,
+    pub peg_ratio: Option<f64>,
+    pub book_value_per_share: Option<f64>,
+    pub dividend_yield: Option<f64>,
+    pub payout_ratio: Option<f64>,
+    #[serde(alias = "e_v_to_e_b_i_t_d_a")]
+    pub ev_to_ebitda: Option<f64>,
+}
+
+#[optimistic_no_ceho]
+pub struct EarningRatios {
+    pub as_of_date: Option<NaiveDate>,
+    pub fiscal_year_end: Option<i64>,
+    pub period: Option<String>,
+    pub report_type: Option<String>,
+    #[serde(alias = "diluted_e_p_s_growth")]
+    pub diluted_eps_growth: Option<f64>,
+    pub dps_growth: Option<f64>,
+    pub equity_per_share_growth: Option<f64>,
+}
+
+/// `beta/markets/fundamentals/ratios`
+#[optimistic_no_ceho]
+pub struct RatioTables {
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub operation_ratios_restate: Vec<OperationRatios>,
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub valuation_ratios: Vec<ValuationRatios>,
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub earning_ratios_restate: Vec<EarningRatios>,
+}
+
+#[optimistic_no_ceho]
+pub struct IncomeStatement {
+    pub as_of_date: Option<NaiveDate>,
+    pub fiscal_year_end: Option<i64>,
+    pub period: Option<String>,
+    pub report_type: Option<String>,
+    pub currency_id: Option<String>,
+    pub total_revenue: Option<f64>,
+    pub cost_of_revenue: Option<f64>,
+    pub gross_profit: Option<f64>,
+    pub operating_expense: Option<f64>,
+    pub operating_income: Option<f64>,
+    pub interest_expense: Option<f64>,
+    pub pretax_income: Option<f64>,
+    pub tax_provision: Option<f64>,
+    pub net_income: Option<f64>,
+    #[serde(alias = "e_b_i_t_d_a")]
+    pub ebitda: Option<f64>,
+}
+
+#[optimistic_no_ceho]
+pub struct BalanceSheet {
+    pub as_of_date: Option<NaiveDate>,
+    pub fiscal_year_end: Option<i64>,
+    pub period: Option<String>,
+    pub report_type: Option<String>,
+    pub currency_id: Option<String>,
+    pub total_assets: Option<f64>,
+    pub current_assets: Option<f64>,
+    pub cash_and_cash_equivalents: Option<f64>,
+    pub inventory: Option<f64>,
+    pub current_liabilities: Option<f64>,
+    pub long_term_debt: Option<f64>,
+    pub total_debt: Option<f64>,
+    pub stockholders_equity: Option<f64>,
+    pub retained_earnings: Option<f64>,
+    pub working_capital: Option<f64>,
+}
+
+#[optimistic_no_ceho]
+pub struct CashFlowStatement {
+    pub as_of_date: Option<NaiveDate>,
+    pub fiscal_year_end: Option<i64>,
+    pub period: Option<String>,
+    pub report_type: Option<String>,
+    pub currency_id: Option<String>,
+    pub operating_cash_flow: Option<f64>,
+    pub investing_cash_flow: Option<f64>,
+    pub financing_cash_flow: Option<f64>,
+    pub capital_expenditure: Option<f64>,
+    pub free_cash_flow: Option<f64>,
+    pub cash_dividends_paid: Option<f64>,
+    pub end_cash_position: Option<f64>,
+}
+
+/// `beta/markets/fundamentals/financials`, one entry per reported period.
+#[optimistic_no_ceho]
+pub struct FinancialTables {
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub income_statement: Vec<IncomeStatement>,
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub balance_sheet: Vec<BalanceSheet>,
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub cash_flow_statement: Vec<CashFlowStatement>,
+}
+
+#[optimistic_no_ceho]
+pub struct PriceStatistics {
+    pub as_of_date: Option<NaiveDate>,
+    pub period: Option<String>,
+    pub average_volume: Option<f64>,
+    pub close_price_to_moving_average: Option<f64>,
+    pub high_price: Option<f64>,
+    pub low_price: Option<f64>,
+    pub moving_average_price: Option<f64>,
+    pub percentage_below_high_price: Option<f64>,
+    pub total_volume: Option<f64>,
+}
+
+#[optimistic_no_ceho]
+pub struct TrailingReturns {
+    pub as_of_date: Option<NaiveDate>,
+    pub period: Option<String>,
+    pub total_return: Option<f64>,
+}
+
+/// `beta/markets/fundamentals/statistics`, keyed by period such as `period_52w`.
+#[optimistic_no_ceho]
+pub struct StatisticsTables {
+    #[serde(default)]
+    pub price_statistics: BTreeMap<String, PriceStatistics>,
+    #[serde(default)]
+    pub trailing_returns: BTreeMap<String, TrailingReturns>,
+}
+
+/// One scheduled corporate event, such as an earnings release.
+#[optimistic_no_c]
+pub struct CorporateCalendar {
+    pub company_id: Option<String>,
+    pub begin_date_time: Option<NaiveDate>,
+    pub end_date_time: Option<NaiveDate>,
+    pub event_type: Option<i64>,
+    pub estimated_date_for_next_event: Option<NaiveDate>,
+    pub event: Option<String>,
+    pub event_fiscal_year: Option<i64>,
+    pub event_status: Option<String>,
+    pub time_zone: Option<String>,
+}
+
+impl CorporateCalendar {
+    /// Tradier doesn't document its `event_type` codes, so this goes by the
+    /// event's description.
+    pub fn is_earnings(&self) -> bool {
+        self.event
+            .as_deref()
+            .is_some_and(|event| event.to_lowercase().contains("earnings"))
+    }
+}
+
+/// `beta/markets/fundamentals/calendars`
+#[optimistic_no_c]
+pub struct CalendarTables {
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub corporate_calendars: Vec<CorporateCalendar>,
+}
+
+#[optimistic_no_ceho]
+pub struct CashDividend {
+    pub share_class_id: Option<String>,
+    pub dividend_type: Option<String>,
+    pub ex_date: Option<NaiveDate>,
+    pub cash_amount: Option<f64>,
+    pub currency_id: Option<String>,
+    pub declaration_date: Option<NaiveDate>,
+    pub frequency: Option<i64>,
+    pub pay_date: Option<NaiveDate>,
+    pub record_date: Option<NaiveDate>,
+}
+
+/// `beta/markets/fundamentals/dividends`
+#[optimistic_no_ceho]
+pub struct DividendTables {
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub cash_dividends: Vec<CashDividend>,
+}
+
+#[optimistic_no_ceho]
+pub struct StockSplit {
+    pub share_class_id: Option<String>,
+    pub ex_date: Option<NaiveDate>,
+    pub adjustment_factor: Option<f64>,
+    pub split_from: Option<f64>,
+    pub split_to: Option<f64>,
+}
+
+#[optimistic_no_ceho]
+pub struct MergerAndAcquisition {
+    pub acquired_company_id: Option<String>,
+    pub parent_company_id: Option<String>,
+    pub cash_amount: Option<f64>,
+    pub currency_id: Option<String>,
+    pub effective_date: Option<NaiveDate>,
+    pub notes: Option<String>,
+}
+
+/// `beta/markets/fundamentals/corporate_actions`
+#[optimistic_no_ceho]
+pub struct CorporateActionTables {
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub stock_splits: Vec<StockSplit>,
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub mergers_and_acquisitions: Vec<MergerAndAcquisition>,
+}
+
+/// The next earnings date and ex-dividend date on or after a given day, if
+/// Tradier knows of one.
+#[optimistic_no_c]
+pub struct UpcomingEvents {
+    pub symbol: String,
+    pub next_earnings: Option<NaiveDate>,
+    pub next_ex_dividend: Option<NaiveDate>,
+}
+
+/// Pairs calendars and dividends by requested symbol, keeping the order of `symbols`.
+fn upcoming_events(
+    symbols: Vec<String>,
+    from: NaiveDate,
+    calendars: Vec<Fundamentals<CalendarTables>>,
+    dividends: Vec<Fundamentals<DividendTables>>,
+) -> Vec<UpcomingEvents> {
+    symbols
+        .into_iter()
+        .map(|symbol| {
+            let next_earnings = calendars
+                .iter()
+                .filter(|f| f.request == symbol)
+                .flat_map(|f| f.tables())
+                .flat_map(|tables| tables.corporate_calendars.iter())
+                .filter(|event| event.is_earnings())
+                .filter_map(|event| event.begin_date_time)
+                .filter(|date| *date >= from)
+                .min();
+            let next_ex_dividend = dividends
+                .iter()
+                .filter(|f| f.request == symbol)
+                .flat_map(|f| f.tables())
+                .flat_map(|tables| tables.cash_dividends.iter())
+                .filter_map(|dividend| dividend.ex_date)
+                .filter(|date| *date >= from)
+                .min();
+            UpcomingEvents {
+                symbol,
+                next_earnings,
+                next_ex_dividend,
+            }
+        })
+        .collect()
+}
+
+#[optimistic_no_c]
+struct Query {
+    symbols: String,
+}
+
+#[cfg(feature = "blocking")]
+impl blocking::TradierClient {
+    fn get_fundamentals<T: for<'de> Deserialize<'de>>(
+        &self,
+        path: &str,
+        symbols: Vec<String>,
+    ) -> Result<Vec<Fundamentals<T>>> {
+        let query = Query {
+            symbols: symbols.join(","),
+        };
+
+        let request = self.build_request_get(path, None::<()>, Some(query));
+        let response: Option<Vec<Fundamentals<T>>> = self.send(request)?;
+
+        Ok(response.unwrap_or_default())
+    }
+
+    pub fn get_company(&self, symbols: Vec<String>) -> Result<Vec<Fundamentals<CompanyTables>>> {
+        self.get_fundamentals("beta/markets/fundamentals/company", symbols)
+    }
+
+    pub fn get_ratios(&self, symbols: Vec<String>) -> Result<Vec<Fundamentals<RatioTables>>> {
+        self.get_fundamentals("beta/markets/fundamentals/ratios", symbols)
+    }
+
+    pub fn get_financials(
+        &self,
+        symbols: Vec<String>,
+    ) -> Result<Vec<Fundamentals<FinancialTables>>> {
+        self.get_fundamentals("beta/markets/fundamentals/financials", symbols)
+    }
+
+    pub fn get_statistics(
+        &self,
+        symbols: Vec<String>,
+    ) -> Result<Vec<Fundamentals<StatisticsTables>>> {
+        self.get_fundamentals("beta/markets/fundamentals/statistics", symbols)
+    }
+
+    pub fn get_calendars(&self, symbols: Vec<String>) -> Result<Vec<Fundamentals<CalendarTables>>> {
+        self.get_fundamentals("beta/markets/fundamentals/calendars", symbols)
+    }
+
+    pub fn get_dividends(&self, symbols: Vec<String>) -> Result<Vec<Fundamentals<DividendTables>>> {
+        self.get_fundamentals("beta/markets/fundamentals/dividends", symbols)
+    }
+
+    pub fn get_corporate_actions(
+        &self,
+        symbols: Vec<String>,
+    ) -> Result<Vec<Fundamentals<CorporateActionTables>>> {
+        self.get_fundamentals("beta/markets/fundamentals/corporate_actions", symbols)
+    }
+
+    /// Next earnings and ex-dividend dates on or after `from`, one entry per
+    /// symbol, e.g. for the symbols of `get_positions`.
+    pub fn get_upcoming_events(
+        &self,
+        symbols: Vec<String>,
+        from: NaiveDate,
+    ) -> Result<Vec<UpcomingEvents>> {
+        let calendars = self.get_calendars(symbols.clone())?;
+        let dividends = self.get_dividends(symbols.clone())?;
+        Ok(upcoming_events(symbols, from, calendars, dividends))
+    }
+}
+
+impl TradierClient {
+    async fn get_fundamentals<T: for<'de> Deserialize<'de>>(
+        &self,
+        path: &str,
+        symbols: Vec<String>,
+    ) -> Result<Vec<Fundamentals<T>>> {
+        let query = Query {
+            symbols: symbols.join(","),
+        };
+
+        let request = self.build_request_get(path, None::<()>, Some(query));
+        let response: Option<Vec<Fundamentals<T>>> = self.send(request).await?;
+
+        Ok(response.unwrap_or_default())
+    }
+
+    pub async fn get_company(
+        &self,
+        symbols: Vec<String>,
+    ) -> Result<Vec<Fundamentals<CompanyTables>>> {
+        self.get_fundamentals("beta/markets/fundamentals/company", symbols)
+            .await
+    }
+
+    pub async fn get_ratios(&self, symbols: Vec<String>) -> Result<Vec<Fundamentals<RatioTables>>> {
+        self.get_fundamentals("beta/markets/fundamentals/ratios", symbols)
+            .await
+    }
+
+    pub async fn get_financials(
+        &self,
+        symbols: Vec<String>,
+    ) -> Result<Vec<Fundamentals<FinancialTables>>> {
+        self.get_fundamentals("beta/markets/fundamentals/financials", symbols)
+            .await
+    }
+
+    pub async fn get_statistics(
+        &self,
+        symbols: Vec<String>,
+    ) -> Result<Vec<Fundamentals<StatisticsTables>>> {
+        self.get_fundamentals("beta/markets/fundamentals/statistics", symbols)
+            .await
+    }
+
+    pub async fn get_calendars(
+        &self,
+        symbols: Vec<String>,
+    ) -> Result<Vec<Fundamentals<CalendarTables>>> {
+        self.get_fundamentals("beta/markets/fundamentals/calendars", symbols)
+            .await
+    }
+
+    pub async fn get_dividends(
+        &self,
+        symbols: Vec<String>,
+    ) -> Result<Vec<Fundamentals<DividendTables>>> {
+        self.get_fundamentals("beta/markets/fundamentals/dividends", symbols)
+            .await
+    }
+
+    pub async fn get_corporate_actions(
+        &self,
+        symbols: Vec<String>,
+    ) -> Result<Vec<Fundamentals<CorporateActionTables>>> {
+        self.get_fundamentals("beta/markets/fundamentals/corporate_actions", symbols)
+            .await
+    }
+
+    /// Next earnings and ex-dividend dates on or after `from`, one entry per
+    /// symbol, e.g. for the symbols of `get_positions`.
+    pub async fn get_upcoming_events(
+        &self,
+        symbols: Vec<String>,
+        from: NaiveDate,
+    ) -> Result<Vec<UpcomingEvents>> {
+        let calendars = self.get_calendars(symbols.clone()).await?;
+        let dividends = self.get_dividends(symbols.clone()).await?;
+        Ok(upcoming_events(symbols, from, calendars, dividends))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use mockito::mock;
+
+    #[cfg(feature = "blocking")]
+    use crate::blocking;
+    use crate::{TradierClient, TradierConfig};
+
+    #[tokio::test]
+    async fn test_get_company() {
+        let _m = mock("GET", "/beta/markets/fundamentals/company?symbols=AAPL")
+            .with_status(200)
+            .with_body(include_str!("test_requests/get_company.json"))
+            .create();
+
+        let client = TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        let response = client.get_company(vec!["AAPL".into()]).await.unwrap();
+        assert_eq!(response[0].request, "AAPL");
+        // The Stock result has no company profile.
+        let profiles: Vec<_> = response[0]
+            .tables()
+            .filter_map(|tables| tables.company_profile.as_ref())
+            .collect();
+        assert_eq!(profiles.len(), 1);
+        assert_eq!(
+            profiles[0].headquarter.as_ref().unwrap().city.as_deref(),
+            Some("Cupertino")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_ratios() {
+        let _m = mock("GET", "/beta/markets/fundamentals/ratios?symbols=AAPL")
+            .with_status(200)
+            .with_body(include_str!("test_requests/get_ratios.json"))
+            .create();
+
+        let client = TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        let response = client.get_ratios(vec!["AAPL".into()]).await.unwrap();
+        let tables: Vec<_> = response[0].tables().collect();
+        assert_eq!(tables[0].operation_ratios_restate.len(), 2);
+        assert_eq!(tables[0].operation_ratios_restate[0].roe, Some(0.6106));
+        assert_eq!(tables[1].valuation_ratios[0].pe_ratio, Some(22.97));
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn test_get_financials() {
+        let _m = mock("GET", "/beta/markets/fundamentals/financials?symbols=AAPL")
+            .with_status(200)
+            .with_body(include_str!("test_requests/get_financials.json"))
+            .create();
+
+        let client = blocking::TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        let response = client.get_financials(vec!["AAPL".into()]).unwrap();
+        let tables = response[0].tables().next().unwrap();
+        assert_eq!(tables.income_statement[0].net_income, Some(55256000000.0));
+        assert_eq!(tables.balance_sheet.len(), 1);
+        assert!(tables.cash_flow_statement.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_statistics() {
+        let _m = mock(
+            "GET",
+            "/beta/markets/fundamentals/statistics?symbols=AAPL%2CXYZ",
+        )
+        .with_status(200)
+        .with_body(include_str!("test_requests/get_statistics.json"))
+        .create();
+
+        let client = TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        let response = client
+            .get_statistics(vec!["AAPL".into(), "XYZ".into()])
+            .await
+            .unwrap();
+        let tables = response[0].tables().next().unwrap();
+        assert_eq!(
+            tables.price_statistics["period_52w"].high_price,
+            Some(268.0)
+        );
+        assert_eq!(
+            tables.trailing_returns["period_1y"].total_return,
+            Some(0.5536)
+        );
+        assert!(response[1].results.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_upcoming_events() {
+        let _calendars = mock(
+            "GET",
+            "/beta/markets/fundamentals/calendars?symbols=AAPL%2CXYZ",
+        )
+        .with_status(200)
+        .with_body(include_str!("test_requests/get_calendars.json"))
+        .create();
+        let _dividends = mock(
+            "GET",
+            "/beta/markets/fundamentals/dividends?symbols=AAPL%2CXYZ",
+        )
+        .with_status(200)
+        .with_body(include_str!("test_requests/get_dividends.json"))
+        .create();
+
+        let client = TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        let events = client
+            .get_upcoming_events(
+                vec!["AAPL".into(), "XYZ".into()],
+                NaiveDate::from_ymd_opt(2019, 11, 1).unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            events[0].next_earnings,
+            NaiveDate::from_ymd_opt(2020, 1, 28)
+        );
+        assert_eq!(
+            events[0].next_ex_dividend,
+            NaiveDate::from_ymd_opt(2019, 11, 7)
+        );
+        assert_eq!(events[1].symbol, "XYZ");
+        assert_eq!(events[1].next_earnings, None);
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn test_get_corporate_actions() {
+        let _m = mock(
+            "GET",
+            "/beta/markets/fundamentals/corporate_actions?symbols=AAPL",
+        )
+        .with_status(200)
+        .with_body(include_str!("test_requests/get_corporate_actions.json"))
+        .create();
+
+        let client = blocking::TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        let response = client.get_corporate_actions(vec!["AAPL".into()]).unwrap();
+        let tables = response[0].tables().next().unwrap();
+        assert_eq!(tables.stock_splits[0].split_to, Some(7.0));
+        assert!(tables.mergers_and_acquisitions.is_empty());
+    }
+}
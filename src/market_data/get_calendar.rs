@@ -0,0 +1,108 @@
+#![allow(non_camel_case_types)]
+
+use chrono::{NaiveDate, NaiveTime};
+use optimistic_derives::*;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "blocking")]
+use crate::blocking;
+use crate::{Result, TradierClient};
+
+#[optimistic]
+pub enum DayStatus {
+    open,
+    closed,
+}
+
+/// A session's start and end, New York time.
+#[optimistic]
+pub struct Hours {
+    pub start: NaiveTime,
+    pub end: NaiveTime,
+}
+
+/// Closed days have no hours; `description` names the holiday when there is one.
+#[optimistic_no_c]
+pub struct Day {
+    pub date: NaiveDate,
+    pub status: DayStatus,
+    pub description: String,
+    pub premarket: Option<Hours>,
+    pub open: Option<Hours>,
+    pub postmarket: Option<Hours>,
+}
+
+#[optimistic_no_c]
+pub struct Days {
+    pub day: Vec<Day>,
+}
+
+#[optimistic_no_c]
+pub struct Calendar {
+    pub month: u32,
+    pub year: i32,
+    pub days: Days,
+}
+
+#[optimistic_no_c]
+pub struct CalendarRoot {
+    pub calendar: Calendar,
+}
+
+#[optimistic]
+struct Query {
+    month: Option<u32>,
+    year: Option<i32>,
+}
+
+#[cfg(feature = "blocking")]
+impl blocking::TradierClient {
+    /// Defaults to the current month when `month` and `year` are left out.
+    pub fn get_calendar(&self, month: Option<u32>, year: Option<i32>) -> Result<CalendarRoot> {
+        let query = Query { month, year };
+
+        let request = self.build_request_get("markets/calendar", None::<()>, Some(query));
+        self.send(request)
+    }
+}
+
+impl TradierClient {
+    /// Defaults to the current month when `month` and `year` are left out.
+    pub async fn get_calendar(
+        &self,
+        month: Option<u32>,
+        year: Option<i32>,
+    ) -> Result<CalendarRoot> {
+        let query = Query { month, year };
+
+        let request = self.build_request_get("markets/calendar", None::<()>, Some(query));
+        self.send(request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::mock;
+
+    use crate::{market_data::get_calendar::DayStatus, TradierClient, TradierConfig};
+
+    #[tokio::test]
+    async fn test_get_calendar() {
+        let _m = mock("GET", "/v1/markets/calendar?month=11&year=2019")
+            .with_status(200)
+            .with_body(include_str!("test_requests/get_calendar.json"))
+            .create();
+
+        let client = TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        let calendar = client
+            .get_calendar(Some(11), Some(2019))
+            .await
+            .unwrap()
+            .calendar;
+        assert_eq!(calendar.days.day.len(), 30);
+        let thanksgiving = &calendar.days.day[27];
+        assert_eq!(thanksgiving.status, DayStatus::closed);
+        assert!(thanksgiving.open.is_none());
+    }
+}
@@ -0,0 +1,74 @@
+#![allow(non_camel_case_types)]
+
+use chrono::{NaiveDate, NaiveTime};
+use optimistic_derives::*;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "blocking")]
+use crate::blocking;
+use crate::{Result, TradierClient};
+
+#[optimistic]
+pub enum MarketState {
+    premarket,
+    open,
+    postmarket,
+    closed,
+}
+
+/// `next_change` is New York time.
+#[optimistic_no_c]
+pub struct Clock {
+    pub date: NaiveDate,
+    pub description: String,
+    pub state: MarketState,
+    pub timestamp: i64,
+    pub next_change: NaiveTime,
+    pub next_state: MarketState,
+}
+
+#[optimistic_no_c]
+pub struct ClockRoot {
+    pub clock: Clock,
+}
+
+#[cfg(feature = "blocking")]
+impl blocking::TradierClient {
+    pub fn get_clock(&self) -> Result<ClockRoot> {
+        let request = self.build_request_get("markets/clock", None::<()>, None::<()>);
+        self.send(request)
+    }
+}
+
+impl TradierClient {
+    pub async fn get_clock(&self) -> Result<ClockRoot> {
+        let request = self.build_request_get("markets/clock", None::<()>, None::<()>);
+        self.send(request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveTime;
+    use mockito::mock;
+
+    use crate::{market_data::get_clock::MarketState, TradierClient, TradierConfig};
+
+    #[tokio::test]
+    async fn test_get_clock() {
+        let _m = mock("GET", "/v1/markets/clock")
+            .with_status(200)
+            .with_body(include_str!("test_requests/get_clock.json"))
+            .create();
+
+        let client = TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        let clock = client.get_clock().await.unwrap().clock;
+        assert_eq!(clock.state, MarketState::open);
+        assert_eq!(clock.next_state, MarketState::postmarket);
+        assert_eq!(
+            clock.next_change,
+            NaiveTime::from_hms_opt(16, 0, 0).unwrap()
+        );
+    }
+}
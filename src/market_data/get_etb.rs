@@ -0,0 +1,215 @@
+use std::{collections::HashSet, sync::Mutex};
+
+use chrono::{NaiveDate, Utc};
+use chrono_tz::America::New_York;
+use optimistic_derives::*;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "blocking")]
+use crate::blocking;
+use crate::{Result, Side, TradierClient, TradierError};
+
+/// An easy-to-borrow entry. Only `symbol` is required, so one odd entry can't
+/// stop the whole list from decoding.
+#[optimistic_no_c]
+pub struct EtbSecurity {
+    pub symbol: String,
+    pub exchange: Option<String>,
+    #[serde(alias = "type")]
+    pub security_type: Option<String>,
+    pub description: Option<String>,
+}
+
+#[optimistic_no_c]
+pub struct EtbSecurities {
+    pub security: Vec<EtbSecurity>,
+}
+
+#[optimistic_no_c]
+struct SingleEtbSecurity {
+    security: EtbSecurity,
+}
+
+#[optimistic_no_c]
+pub struct EtbRoot {
+    pub securities: EtbSecurities,
+}
+
+#[optimistic_no_c]
+struct SingleEtbRoot {
+    securities: Option<SingleEtbSecurity>,
+}
+
+#[optimistic_no_c]
+#[serde(untagged)]
+enum EtbEnum {
+    Unit(SingleEtbRoot),
+    Vec(EtbRoot),
+}
+
+impl From<EtbEnum> for EtbRoot {
+    fn from(item: EtbEnum) -> EtbRoot {
+        match item {
+            EtbEnum::Unit(unit) => EtbRoot {
+                securities: EtbSecurities {
+                    security: match unit.securities {
+                        Some(sec) => vec![sec.security],
+                        None => vec![],
+                    },
+                },
+            },
+            EtbEnum::Vec(root) => root,
+        }
+    }
+}
+
+/// Easy-to-borrow symbols, kept until the New York trading day changes.
+#[derive(Debug, Default)]
+pub(crate) struct EtbCache {
+    symbols: Mutex<Option<(NaiveDate, HashSet<String>)>>,
+}
+
+impl EtbCache {
+    fn today() -> NaiveDate {
+        Utc::now().with_timezone(&New_York).date_naive()
+    }
+
+    /// `None` until the list has been stored for today.
+    fn contains(&self, symbol: &str) -> Option<bool> {
+        match &*self.symbols.lock().unwrap() {
+            Some((date, symbols)) if *date == Self::today() => Some(symbols.contains(symbol)),
+            _ => None,
+        }
+    }
+
+    /// Stores today's list and returns whether it has `symbol`.
+    fn store(&self, etb: EtbRoot, symbol: &str) -> bool {
+        let symbols: HashSet<String> = etb
+            .securities
+            .security
+            .into_iter()
+            .map(|security| security.symbol)
+            .collect();
+        let contains = symbols.contains(symbol);
+        *self.symbols.lock().unwrap() = Some((Self::today(), symbols));
+        contains
+    }
+}
+
+fn not_borrowable(symbol: &str) -> TradierError {
+    TradierError::InvalidOrder(format!(
+        "{} is not on the easy-to-borrow list, so it can't be sold short",
+        symbol
+    ))
+}
+
+#[cfg(feature = "blocking")]
+impl blocking::TradierClient {
+    pub fn get_etb(&self) -> Result<EtbRoot> {
+        let request = self.build_request_get("markets/etb", None::<()>, None::<()>);
+        let response: EtbEnum = self.send(request)?;
+
+        Ok(response.into())
+    }
+
+    /// Checks `symbol` against the easy-to-borrow list, fetching it at most
+    /// once per trading day.
+    pub fn is_easy_to_borrow(&self, symbol: &str) -> Result<bool> {
+        match self.etb_cache().contains(symbol) {
+            Some(etb) => Ok(etb),
+            None => Ok(self.etb_cache().store(self.get_etb()?, symbol)),
+        }
+    }
+
+    /// Rejects `sell_short` on symbols that aren't easy to borrow when the
+    /// client was built with
+    /// [`check_short_sales`](crate::TradierClientBuilder::check_short_sales).
+    pub(crate) fn check_short_sale(&self, symbol: &str, side: Side) -> Result<()> {
+        if !self.checks_short_sales() || side != Side::sell_short {
+            return Ok(());
+        }
+        match self.is_easy_to_borrow(symbol)? {
+            true => Ok(()),
+            false => Err(not_borrowable(symbol)),
+        }
+    }
+}
+
+impl TradierClient {
+    pub async fn get_etb(&self) -> Result<EtbRoot> {
+        let request = self.build_request_get("markets/etb", None::<()>, None::<()>);
+        let response: EtbEnum = self.send(request).await?;
+
+        Ok(response.into())
+    }
+
+    /// Checks `symbol` against the easy-to-borrow list, fetching it at most
+    /// once per trading day.
+    pub async fn is_easy_to_borrow(&self, symbol: &str) -> Result<bool> {
+        match self.etb_cache().contains(symbol) {
+            Some(etb) => Ok(etb),
+            None => Ok(self.etb_cache().store(self.get_etb().await?, symbol)),
+        }
+    }
+
+    /// Rejects `sell_short` on symbols that aren't easy to borrow when the
+    /// client was built with
+    /// [`check_short_sales`](crate::TradierClientBuilder::check_short_sales).
+    pub(crate) async fn check_short_sale(&self, symbol: &str, side: Side) -> Result<()> {
+        if !self.checks_short_sales() || side != Side::sell_short {
+            return Ok(());
+        }
+        match self.is_easy_to_borrow(symbol).await? {
+            true => Ok(()),
+            false => Err(not_borrowable(symbol)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::mock;
+
+    use crate::{Class, Duration, OrderType, Side, TradierClient, TradierConfig, TradierError};
+
+    #[tokio::test]
+    async fn test_short_sale_check() {
+        let etb = mock("GET", "/v1/markets/etb")
+            .with_status(200)
+            .with_body(include_str!("test_requests/get_etb.json"))
+            .expect(1)
+            .create();
+        let _order = mock("POST", "/v1/accounts/VA000000/orders")
+            .with_status(200)
+            .with_body(include_str!("../trading/test_requests/post_order.json"))
+            .create();
+
+        let client = TradierClient::builder(TradierConfig::new("xxx", mockito::server_url()))
+            .check_short_sales(true)
+            .build()
+            .unwrap();
+        let short = |symbol: &str| {
+            client.post_order(
+                "VA000000".into(),
+                Class::equity,
+                symbol.into(),
+                Side::sell_short,
+                100,
+                OrderType::market,
+                Duration::day,
+                None,
+                None,
+                None,
+            )
+        };
+
+        assert!(short("SCS").await.is_ok());
+        match short("GME").await {
+            Err(TradierError::InvalidOrder(message)) => assert!(message.contains("GME")),
+            other => panic!("unexpected {:?}", other),
+        }
+        assert!(client.is_easy_to_borrow("SPY").await.unwrap());
+        assert!(client.is_easy_to_borrow("BRK.WS").await.unwrap());
+        etb.assert();
+    }
+}
@@ -0,0 +1,235 @@
+#![allow(non_camel_case_types)]
+
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+use chrono_tz::America::New_York;
+use optimistic_derives::*;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "blocking")]
+use crate::blocking;
+use crate::{market_data::get_time_and_sales::SessionFilter, Result, TradierClient};
+
+#[optimistic]
+pub enum Interval {
+    daily,
+    weekly,
+    monthly,
+}
+
+#[optimistic_no_ceho]
+struct NaiveBar {
+    date: NaiveDate,
+    open: f64,
+    high: f64,
+    low: f64,
+    close: f64,
+    volume: i64,
+}
+
+/// A daily, weekly or monthly bar. `time` is midnight New York time on `date`,
+/// converted to UTC.
+#[optimistic_no_ceho]
+pub struct Bar {
+    pub date: NaiveDate,
+    pub time: DateTime<Utc>,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: i64,
+}
+
+impl From<NaiveBar> for Bar {
+    fn from(item: NaiveBar) -> Self {
+        let midnight = item.date.and_hms_opt(0, 0, 0).unwrap();
+        let time = New_York.from_local_datetime(&midnight).unwrap();
+        Bar {
+            date: item.date,
+            time: time.with_timezone(&Utc),
+            open: item.open,
+            high: item.high,
+            low: item.low,
+            close: item.close,
+            volume: item.volume,
+        }
+    }
+}
+
+#[optimistic_no_ceho]
+struct NaiveHistory {
+    day: Vec<NaiveBar>,
+}
+
+#[optimistic_no_ceho]
+struct SingleNaiveHistory {
+    day: NaiveBar,
+}
+
+#[optimistic_no_ceho]
+struct NaiveHistoryRoot {
+    history: NaiveHistory,
+}
+
+#[optimistic_no_ceho]
+struct SingleNaiveHistoryRoot {
+    history: Option<SingleNaiveHistory>,
+}
+
+#[optimistic_no_ceho]
+#[serde(untagged)]
+enum HistoryEnum {
+    Unit(SingleNaiveHistoryRoot),
+    Vec(NaiveHistoryRoot),
+}
+
+#[optimistic_no_ceho]
+pub struct History {
+    pub day: Vec<Bar>,
+}
+
+#[optimistic_no_ceho]
+pub struct HistoryRoot {
+    pub history: History,
+}
+
+impl From<HistoryEnum> for HistoryRoot {
+    fn from(item: HistoryEnum) -> HistoryRoot {
+        let day = match item {
+            HistoryEnum::Unit(unit) => match unit.history {
+                Some(history) => vec![history.day.into()],
+                None => vec![],
+            },
+            HistoryEnum::Vec(root) => root.history.day.into_iter().map(|d| d.into()).collect(),
+        };
+        HistoryRoot {
+            history: History { day },
+        }
+    }
+}
+
+#[optimistic_no_c]
+struct Query {
+    symbol: String,
+    interval: Option<Interval>,
+    start: Option<NaiveDate>,
+    end: Option<NaiveDate>,
+    session_filter: Option<SessionFilter>,
+}
+
+#[cfg(feature = "blocking")]
+impl blocking::TradierClient {
+    /// `markets/history`; named apart from the account `get_history`.
+    pub fn get_market_history(
+        &self,
+        symbol: String,
+        interval: Option<Interval>,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+        session_filter: Option<SessionFilter>,
+    ) -> Result<HistoryRoot> {
+        let query = Query {
+            symbol,
+            interval,
+            start,
+            end,
+            session_filter,
+        };
+
+        let request = self.build_request_get("markets/history", None::<()>, Some(query));
+        let response: HistoryEnum = self.send(request)?;
+
+        Ok(response.into())
+    }
+}
+
+impl TradierClient {
+    /// `markets/history`; named apart from the account `get_history`.
+    pub async fn get_market_history(
+        &self,
+        symbol: String,
+        interval: Option<Interval>,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+        session_filter: Option<SessionFilter>,
+    ) -> Result<HistoryRoot> {
+        let query = Query {
+            symbol,
+            interval,
+            start,
+            end,
+            session_filter,
+        };
+
+        let request = self.build_request_get("markets/history", None::<()>, Some(query));
+        let response: HistoryEnum = self.send(request).await?;
+
+        Ok(response.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use mockito::mock;
+
+    #[cfg(feature = "blocking")]
+    use crate::blocking;
+    use crate::{market_data::get_history::Interval, TradierClient, TradierConfig};
+
+    #[tokio::test]
+    async fn test_get_market_history() {
+        let _m = mock(
+            "GET",
+            "/v1/markets/history?symbol=AAPL&interval=daily&start=2019-01-01&end=2019-01-05",
+        )
+        .with_status(200)
+        .with_body(include_str!("test_requests/get_history.json"))
+        .create();
+
+        let client = TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        let response = client
+            .get_market_history(
+                "AAPL".into(),
+                Some(Interval::daily),
+                NaiveDate::from_ymd_opt(2019, 1, 1),
+                NaiveDate::from_ymd_opt(2019, 1, 5),
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.history.day.len(), 3);
+        assert_eq!(
+            response.history.day[0].time.to_rfc3339(),
+            "2019-01-02T05:00:00+00:00"
+        );
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn test_get_market_history_single() {
+        let _m = mock("GET", "/v1/markets/history?symbol=SPY")
+            .with_status(200)
+            .with_body(include_str!("test_requests/get_history_single.json"))
+            .create();
+
+        let client = blocking::TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        let response = client.get_market_history("SPY".into(), None, None, None, None);
+        assert_eq!(response.unwrap().history.day.len(), 1);
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn test_get_market_history_null() {
+        let _m = mock("GET", "/v1/markets/history?symbol=XYZ")
+            .with_status(200)
+            .with_body(include_str!("test_requests/get_history_null.json"))
+            .create();
+
+        let client = blocking::TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        let response = client.get_market_history("XYZ".into(), None, None, None, None);
+        assert!(response.unwrap().history.day.is_empty());
+    }
+}
@@ -0,0 +1,201 @@
+#![allow(non_camel_case_types)]
+
+use chrono::NaiveDate;
+use optimistic_derives::*;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "blocking")]
+use crate::blocking;
+use crate::{
+    market_data::get_quotes::{Greeks, OptionType, QuoteType},
+    Result, TradierClient,
+};
+
+#[optimistic_no_ceho]
+pub struct OptionContract {
+    pub symbol: String,
+    pub description: String,
+    pub exch: String,
+    #[serde(alias = "type")]
+    pub quote_type: QuoteType,
+    pub last: Option<f64>,
+    pub change: Option<f64>,
+    pub volume: i64,
+    pub open: Option<f64>,
+    pub high: Option<f64>,
+    pub low: Option<f64>,
+    pub close: Option<f64>,
+    pub bid: Option<f64>,
+    pub ask: Option<f64>,
+    pub underlying: String,
+    pub strike: f64,
+    pub change_percentage: Option<f64>,
+    pub average_volume: i64,
+    pub last_volume: i64,
+    pub trade_date: i64,
+    pub prevclose: Option<f64>,
+    pub week_52_high: Option<f64>,
+    pub week_52_low: Option<f64>,
+    pub bidsize: i64,
+    pub bidexch: Option<String>,
+    pub bid_date: i64,
+    pub asksize: i64,
+    pub askexch: Option<String>,
+    pub ask_date: i64,
+    pub open_interest: i64,
+    pub contract_size: i64,
+    pub expiration_date: NaiveDate,
+    pub expiration_type: String,
+    pub option_type: OptionType,
+    pub root_symbol: String,
+    pub greeks: Option<Greeks>,
+}
+
+#[optimistic_no_ceho]
+struct SingleOptions {
+    option: OptionContract,
+}
+
+#[optimistic_no_ceho]
+struct SingleOptionChainRoot {
+    options: Option<SingleOptions>,
+}
+
+#[optimistic_no_ceho]
+pub struct Options {
+    pub option: Vec<OptionContract>,
+}
+
+#[optimistic_no_ceho]
+pub struct GetOptionChains {
+    pub options: Options,
+}
+
+#[optimistic_no_ceho]
+#[serde(untagged)]
+enum OptionChainEnum {
+    Unit(SingleOptionChainRoot),
+    Vec(GetOptionChains),
+}
+
+impl From<OptionChainEnum> for GetOptionChains {
+    fn from(item: OptionChainEnum) -> GetOptionChains {
+        match item {
+            OptionChainEnum::Unit(unit) => GetOptionChains {
+                options: Options {
+                    option: match unit.options {
+                        Some(options) => vec![options.option],
+                        None => vec![],
+                    },
+                },
+            },
+            OptionChainEnum::Vec(root) => root,
+        }
+    }
+}
+
+#[optimistic_no_c]
+struct Query {
+    symbol: String,
+    expiration: NaiveDate,
+    greeks: bool,
+}
+
+#[cfg(feature = "blocking")]
+impl blocking::TradierClient {
+    pub fn get_option_chains(
+        &self,
+        symbol: String,
+        expiration: NaiveDate,
+        greeks: Option<bool>,
+    ) -> Result<GetOptionChains> {
+        let query = Query {
+            symbol,
+            expiration,
+            greeks: greeks.unwrap_or(false),
+        };
+
+        let request = self.build_request_get("markets/options/chains", None::<()>, Some(query));
+        let response: OptionChainEnum = self.send(request)?;
+
+        Ok(response.into())
+    }
+}
+
+impl TradierClient {
+    pub async fn get_option_chains(
+        &self,
+        symbol: String,
+        expiration: NaiveDate,
+        greeks: Option<bool>,
+    ) -> Result<GetOptionChains> {
+        let query = Query {
+            symbol,
+            expiration,
+            greeks: greeks.unwrap_or(false),
+        };
+
+        let request = self.build_request_get("markets/options/chains", None::<()>, Some(query));
+        let response: OptionChainEnum = self.send(request).await?;
+
+        Ok(response.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use mockito::mock;
+
+    #[cfg(feature = "blocking")]
+    use crate::blocking;
+    use crate::{market_data::get_quotes::OptionType, TradierClient, TradierConfig};
+
+    #[tokio::test]
+    async fn test_get_option_chains() {
+        let _m = mock(
+            "GET",
+            "/v1/markets/options/chains?symbol=VXX&expiration=2019-05-17&greeks=true",
+        )
+        .with_status(200)
+        .with_body(include_str!("test_requests/get_option_chains.json"))
+        .create();
+
+        let client = TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        let response = client
+            .get_option_chains(
+                "VXX".into(),
+                NaiveDate::from_ymd_opt(2019, 5, 17).unwrap(),
+                Some(true),
+            )
+            .await
+            .unwrap();
+        let call = &response.options.option[1];
+        assert_eq!(call.option_type, OptionType::call);
+        assert_eq!(call.greeks.as_ref().unwrap().delta, 1.0);
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn test_get_option_chains_null() {
+        let _m = mock(
+            "GET",
+            "/v1/markets/options/chains?symbol=VXX&expiration=2019-05-18&greeks=false",
+        )
+        .with_status(200)
+        .with_body(include_str!("test_requests/get_option_chains_null.json"))
+        .create();
+
+        let client = blocking::TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        let response = client
+            .get_option_chains(
+                "VXX".into(),
+                NaiveDate::from_ymd_opt(2019, 5, 18).unwrap(),
+                None,
+            )
+            .unwrap();
+        assert!(response.options.option.is_empty());
+    }
+}
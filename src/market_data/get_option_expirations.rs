@@ -0,0 +1,228 @@
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+
+use chrono::NaiveDate;
+use optimistic_derives::*;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "blocking")]
+use crate::blocking;
+use crate::{
+    market_data::get_option_strikes::{NaiveStrikes, Strikes},
+    Result, TradierClient,
+};
+
+#[optimistic_no_ceho]
+struct NaiveExpiration {
+    date: NaiveDate,
+    contract_size: Option<i64>,
+    expiration_type: Option<String>,
+    strikes: Option<NaiveStrikes>,
+}
+
+#[optimistic_no_ceho]
+#[serde(untagged)]
+enum ExpirationEnum {
+    Unit(NaiveExpiration),
+    Vec(Vec<NaiveExpiration>),
+}
+
+#[optimistic_no_c]
+#[serde(untagged)]
+enum DateEnum {
+    Unit(NaiveDate),
+    Vec(Vec<NaiveDate>),
+}
+
+/// Tradier returns a bare `date` list unless `strikes`, `contractSize` or
+/// `expirationType` is requested, in which case it returns `expiration`
+/// objects instead.
+#[optimistic_no_ceho]
+struct NaiveExpirations {
+    date: Option<DateEnum>,
+    expiration: Option<ExpirationEnum>,
+}
+
+#[optimistic_no_ceho]
+struct NaiveExpirationsRoot {
+    expirations: Option<NaiveExpirations>,
+}
+
+#[optimistic_no_ceho]
+pub struct Expiration {
+    pub date: NaiveDate,
+    pub contract_size: Option<i64>,
+    pub expiration_type: Option<String>,
+    pub strikes: Vec<f64>,
+}
+
+impl From<NaiveDate> for Expiration {
+    fn from(date: NaiveDate) -> Self {
+        Expiration {
+            date,
+            contract_size: None,
+            expiration_type: None,
+            strikes: vec![],
+        }
+    }
+}
+
+impl From<NaiveExpiration> for Expiration {
+    fn from(item: NaiveExpiration) -> Self {
+        Expiration {
+            date: item.date,
+            contract_size: item.contract_size,
+            expiration_type: item.expiration_type,
+            strikes: item
+                .strikes
+                .map(|strikes| Strikes::from(strikes).strike)
+                .unwrap_or_default(),
+        }
+    }
+}
+
+#[optimistic_no_ceho]
+pub struct Expirations {
+    pub expiration: Vec<Expiration>,
+}
+
+#[optimistic_no_ceho]
+pub struct GetOptionExpirations {
+    pub expirations: Expirations,
+}
+
+impl From<NaiveExpirationsRoot> for GetOptionExpirations {
+    fn from(item: NaiveExpirationsRoot) -> Self {
+        let expiration = match item.expirations {
+            Some(NaiveExpirations {
+                expiration: Some(ExpirationEnum::Unit(unit)),
+                ..
+            }) => vec![unit.into()],
+            Some(NaiveExpirations {
+                expiration: Some(ExpirationEnum::Vec(expirations)),
+                ..
+            }) => expirations.into_iter().map(|e| e.into()).collect(),
+            Some(NaiveExpirations {
+                date: Some(DateEnum::Unit(date)),
+                ..
+            }) => vec![date.into()],
+            Some(NaiveExpirations {
+                date: Some(DateEnum::Vec(dates)),
+                ..
+            }) => dates.into_iter().map(|d| d.into()).collect(),
+            _ => vec![],
+        };
+        GetOptionExpirations {
+            expirations: Expirations { expiration },
+        }
+    }
+}
+
+#[optimistic_no_c]
+struct Query {
+    symbol: String,
+    includeAllRoots: Option<bool>,
+    strikes: Option<bool>,
+    contractSize: Option<bool>,
+    expirationType: Option<bool>,
+}
+
+#[cfg(feature = "blocking")]
+impl blocking::TradierClient {
+    pub fn get_option_expirations(
+        &self,
+        symbol: String,
+        include_all_roots: Option<bool>,
+        strikes: Option<bool>,
+        contract_size: Option<bool>,
+        expiration_type: Option<bool>,
+    ) -> Result<GetOptionExpirations> {
+        let query = Query {
+            symbol,
+            includeAllRoots: include_all_roots,
+            strikes,
+            contractSize: contract_size,
+            expirationType: expiration_type,
+        };
+
+        let request =
+            self.build_request_get("markets/options/expirations", None::<()>, Some(query));
+        let response: NaiveExpirationsRoot = self.send(request)?;
+
+        Ok(response.into())
+    }
+}
+
+impl TradierClient {
+    pub async fn get_option_expirations(
+        &self,
+        symbol: String,
+        include_all_roots: Option<bool>,
+        strikes: Option<bool>,
+        contract_size: Option<bool>,
+        expiration_type: Option<bool>,
+    ) -> Result<GetOptionExpirations> {
+        let query = Query {
+            symbol,
+            includeAllRoots: include_all_roots,
+            strikes,
+            contractSize: contract_size,
+            expirationType: expiration_type,
+        };
+
+        let request =
+            self.build_request_get("markets/options/expirations", None::<()>, Some(query));
+        let response: NaiveExpirationsRoot = self.send(request).await?;
+
+        Ok(response.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::mock;
+
+    #[cfg(feature = "blocking")]
+    use crate::blocking;
+    use crate::{TradierClient, TradierConfig};
+
+    #[tokio::test]
+    async fn test_get_option_expirations() {
+        let _m = mock(
+            "GET",
+            "/v1/markets/options/expirations?symbol=VXX&includeAllRoots=true&strikes=true",
+        )
+        .with_status(200)
+        .with_body(include_str!("test_requests/get_option_expirations.json"))
+        .create();
+
+        let client = TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        let response = client
+            .get_option_expirations("VXX".into(), Some(true), Some(true), None, None)
+            .await
+            .unwrap();
+        let expirations = response.expirations.expiration;
+        assert_eq!(expirations.len(), 2);
+        assert_eq!(expirations[0].strikes, vec![13.0, 14.0, 15.0, 16.0]);
+        assert_eq!(expirations[1].strikes, vec![16.0]);
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn test_get_option_expirations_dates() {
+        let _m = mock("GET", "/v1/markets/options/expirations?symbol=VXX")
+            .with_status(200)
+            .with_body(include_str!(
+                "test_requests/get_option_expirations_dates.json"
+            ))
+            .create();
+
+        let client = blocking::TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        let response = client
+            .get_option_expirations("VXX".into(), None, None, None, None)
+            .unwrap();
+        assert_eq!(response.expirations.expiration.len(), 3);
+    }
+}
@@ -0,0 +1,122 @@
+#![allow(non_camel_case_types)]
+
+use chrono::NaiveDate;
+use optimistic_derives::*;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "blocking")]
+use crate::blocking;
+use crate::{Result, TradierClient};
+
+#[optimistic_no_ceho]
+#[serde(untagged)]
+pub(crate) enum StrikeEnum {
+    Unit(f64),
+    Vec(Vec<f64>),
+}
+
+#[optimistic_no_ceho]
+pub(crate) struct NaiveStrikes {
+    pub(crate) strike: StrikeEnum,
+}
+
+impl From<NaiveStrikes> for Strikes {
+    fn from(item: NaiveStrikes) -> Self {
+        Strikes {
+            strike: match item.strike {
+                StrikeEnum::Unit(strike) => vec![strike],
+                StrikeEnum::Vec(strikes) => strikes,
+            },
+        }
+    }
+}
+
+#[optimistic_no_ceho]
+struct NaiveStrikesRoot {
+    strikes: Option<NaiveStrikes>,
+}
+
+#[optimistic_no_ceho]
+pub struct Strikes {
+    pub strike: Vec<f64>,
+}
+
+#[optimistic_no_ceho]
+pub struct GetOptionStrikes {
+    pub strikes: Strikes,
+}
+
+impl From<NaiveStrikesRoot> for GetOptionStrikes {
+    fn from(item: NaiveStrikesRoot) -> Self {
+        GetOptionStrikes {
+            strikes: match item.strikes {
+                Some(strikes) => strikes.into(),
+                None => Strikes { strike: vec![] },
+            },
+        }
+    }
+}
+
+#[optimistic_no_c]
+struct Query {
+    symbol: String,
+    expiration: NaiveDate,
+}
+
+#[cfg(feature = "blocking")]
+impl blocking::TradierClient {
+    pub fn get_option_strikes(
+        &self,
+        symbol: String,
+        expiration: NaiveDate,
+    ) -> Result<GetOptionStrikes> {
+        let query = Query { symbol, expiration };
+
+        let request = self.build_request_get("markets/options/strikes", None::<()>, Some(query));
+        let response: NaiveStrikesRoot = self.send(request)?;
+
+        Ok(response.into())
+    }
+}
+
+impl TradierClient {
+    pub async fn get_option_strikes(
+        &self,
+        symbol: String,
+        expiration: NaiveDate,
+    ) -> Result<GetOptionStrikes> {
+        let query = Query { symbol, expiration };
+
+        let request = self.build_request_get("markets/options/strikes", None::<()>, Some(query));
+        let response: NaiveStrikesRoot = self.send(request).await?;
+
+        Ok(response.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use mockito::mock;
+
+    use crate::{TradierClient, TradierConfig};
+
+    #[tokio::test]
+    async fn test_get_option_strikes() {
+        let _m = mock(
+            "GET",
+            "/v1/markets/options/strikes?symbol=VXX&expiration=2019-05-17",
+        )
+        .with_status(200)
+        .with_body(include_str!("test_requests/get_option_strikes.json"))
+        .create();
+
+        let client = TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        let response = client
+            .get_option_strikes("VXX".into(), NaiveDate::from_ymd_opt(2019, 5, 17).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.strikes.strike, vec![5.0, 6.0, 7.0, 8.0, 9.0, 10.0]);
+    }
+}
@@ -1,10 +1,11 @@
 #![allow(non_camel_case_types)]
-use chrono::NaiveDate;
-use eyre::Result;
+use chrono::{NaiveDate, NaiveDateTime};
 use optimistic_derives::*;
 use serde::{Deserialize, Serialize};
 
-use crate::{build_request_get, TradierConfig};
+#[cfg(feature = "blocking")]
+use crate::blocking;
+use crate::{Result, TradierClient};
 
 #[optimistic]
 pub enum QuoteType {
@@ -15,12 +16,58 @@ pub enum QuoteType {
     mutual_fund,
 }
 
+impl std::fmt::Display for QuoteType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
 #[optimistic]
 pub enum OptionType {
     put,
     call,
 }
 
+/// Tradier formats `updated_at` as `2019-05-06 19:59:08` rather than ISO 8601.
+mod updated_at_format {
+    use chrono::NaiveDateTime;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    const FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+    pub fn serialize<S: Serializer>(
+        time: &NaiveDateTime,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(&time.format(FORMAT).to_string())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<NaiveDateTime, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        NaiveDateTime::parse_from_str(&s, FORMAT).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Greeks and implied volatilities, returned when `greeks=true` is requested.
+/// `updated_at` is as reported by Tradier, without a time zone.
+#[optimistic_no_ceho]
+pub struct Greeks {
+    pub delta: f64,
+    pub gamma: f64,
+    pub theta: f64,
+    pub vega: f64,
+    pub rho: f64,
+    pub phi: f64,
+    pub bid_iv: f64,
+    pub mid_iv: f64,
+    pub ask_iv: f64,
+    pub smv_vol: f64,
+    #[serde(with = "updated_at_format")]
+    pub updated_at: NaiveDateTime,
+}
+
 #[optimistic_no_ceho]
 pub struct Quote {
     pub symbol: String,
@@ -58,11 +105,15 @@ pub struct Quote {
     pub option_type: Option<OptionType>,
     pub root_symbols: Option<String>,
     pub root_symbol: Option<String>,
+    pub greeks: Option<Greeks>,
 }
 
+/// Symbols Tradier couldn't quote are listed in `unmatched_symbols` rather than
+/// failing the request.
 #[optimistic_no_ceho]
 pub struct Quotes {
     pub quote: Vec<Quote>,
+    pub unmatched_symbols: Vec<String>,
 }
 
 #[optimistic_no_ceho]
@@ -70,57 +121,272 @@ pub struct GetQuotes {
     pub quotes: Quotes,
 }
 
-#[optimistic]
+#[optimistic_no_ceho]
+#[serde(untagged)]
+enum QuoteEnum {
+    Unit(Quote),
+    Vec(Vec<Quote>),
+}
+
+#[optimistic_no_c]
+#[serde(untagged)]
+enum SymbolEnum {
+    Unit(String),
+    Vec(Vec<String>),
+}
+
+#[optimistic_no_c]
+struct NaiveUnmatchedSymbols {
+    symbol: SymbolEnum,
+}
+
+#[optimistic_no_ceho]
+struct NaiveQuotes {
+    quote: Option<QuoteEnum>,
+    unmatched_symbols: Option<NaiveUnmatchedSymbols>,
+}
+
+#[optimistic_no_ceho]
+struct NaiveQuotesRoot {
+    quotes: Option<NaiveQuotes>,
+}
+
+impl From<NaiveQuotesRoot> for GetQuotes {
+    fn from(item: NaiveQuotesRoot) -> Self {
+        let (quote, unmatched_symbols) = match item.quotes {
+            Some(quotes) => (
+                match quotes.quote {
+                    Some(QuoteEnum::Unit(quote)) => vec![quote],
+                    Some(QuoteEnum::Vec(quotes)) => quotes,
+                    None => vec![],
+                },
+                match quotes.unmatched_symbols.map(|unmatched| unmatched.symbol) {
+                    Some(SymbolEnum::Unit(symbol)) => vec![symbol],
+                    Some(SymbolEnum::Vec(symbols)) => symbols,
+                    None => vec![],
+                },
+            ),
+            None => (vec![], vec![]),
+        };
+        GetQuotes {
+            quotes: Quotes {
+                quote,
+                unmatched_symbols,
+            },
+        }
+    }
+}
+
+impl GetQuotes {
+    fn extend(&mut self, other: GetQuotes) {
+        self.quotes.quote.extend(other.quotes.quote);
+        self.quotes
+            .unmatched_symbols
+            .extend(other.quotes.unmatched_symbols);
+    }
+}
+
+/// Symbols sent per request by `get_quotes_bulk`.
+pub const QUOTE_CHUNK_SIZE: usize = 1000;
+
+/// Sent as the query for GET and as the form body for POST.
+#[optimistic_no_c]
 struct Query {
+    symbols: String,
     greeks: bool,
 }
 
-pub fn get_quotes(
-    config: &TradierConfig,
-    symbols: Vec<String>,
-    greeks: Option<bool>,
-) -> Result<GetQuotes> {
-    let query = Query {
-        greeks: greeks.unwrap_or(false),
-    };
+#[cfg(feature = "blocking")]
+impl blocking::TradierClient {
+    pub fn get_quotes(&self, symbols: Vec<String>, greeks: Option<bool>) -> Result<GetQuotes> {
+        let query = Query {
+            symbols: symbols.join(","),
+            greeks: greeks.unwrap_or(false),
+        };
+
+        let request = self.build_request_get("markets/quotes", None::<()>, Some(query));
+        let response: NaiveQuotesRoot = self.send(request)?;
 
-    let request = build_request_get(
-        config,
-        &format!("markets/quotes?{}", symbols.join(",")),
-        None::<()>,
-        Some(query),
-    );
-    let response: GetQuotes = request.send()?.json()?;
+        Ok(response.into())
+    }
+
+    /// Quotes any number of symbols through the POST variant of `markets/quotes`,
+    /// [`QUOTE_CHUNK_SIZE`] symbols per request, merging the results.
+    pub fn get_quotes_bulk(&self, symbols: Vec<String>, greeks: Option<bool>) -> Result<GetQuotes> {
+        let mut quotes = GetQuotes {
+            quotes: Quotes {
+                quote: vec![],
+                unmatched_symbols: vec![],
+            },
+        };
+        for chunk in symbols.chunks(QUOTE_CHUNK_SIZE) {
+            let body = Query {
+                symbols: chunk.join(","),
+                greeks: greeks.unwrap_or(false),
+            };
 
-    Ok(response)
+            let request = self.build_request_post("markets/quotes", Some(body), None::<()>);
+            let response: NaiveQuotesRoot = self.send(request)?;
+            quotes.extend(response.into());
+        }
+
+        Ok(quotes)
+    }
+}
+
+impl TradierClient {
+    pub async fn get_quotes(
+        &self,
+        symbols: Vec<String>,
+        greeks: Option<bool>,
+    ) -> Result<GetQuotes> {
+        let query = Query {
+            symbols: symbols.join(","),
+            greeks: greeks.unwrap_or(false),
+        };
+
+        let request = self.build_request_get("markets/quotes", None::<()>, Some(query));
+        let response: NaiveQuotesRoot = self.send(request).await?;
+
+        Ok(response.into())
+    }
+
+    /// Quotes any number of symbols through the POST variant of `markets/quotes`,
+    /// [`QUOTE_CHUNK_SIZE`] symbols per request, merging the results.
+    pub async fn get_quotes_bulk(
+        &self,
+        symbols: Vec<String>,
+        greeks: Option<bool>,
+    ) -> Result<GetQuotes> {
+        let mut quotes = GetQuotes {
+            quotes: Quotes {
+                quote: vec![],
+                unmatched_symbols: vec![],
+            },
+        };
+        for chunk in symbols.chunks(QUOTE_CHUNK_SIZE) {
+            let body = Query {
+                symbols: chunk.join(","),
+                greeks: greeks.unwrap_or(false),
+            };
+
+            let request = self.build_request_post("markets/quotes", Some(body), None::<()>);
+            let response: NaiveQuotesRoot = self.send(request).await?;
+            quotes.extend(response.into());
+        }
+
+        Ok(quotes)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use mockito::mock;
+    use mockito::{mock, Matcher};
 
-    use crate::{market_data::get_quotes::get_quotes, TradierConfig};
+    #[cfg(feature = "blocking")]
+    use crate::blocking;
+    use crate::{TradierClient, TradierConfig};
 
+    #[cfg(feature = "blocking")]
     #[test]
     fn test_get_quotes() {
         let _m = mock(
             "GET",
-            "/v1/markets/quotes?AAPL,VXX190517P00016000&greeks=false",
+            "/v1/markets/quotes?symbols=AAPL%2CVXX190517P00016000&greeks=false",
         )
         .with_status(200)
         .with_body(include_str!("test_requests/get_quotes.json"))
         .create();
 
-        let config = TradierConfig {
-            token: "xxx".into(),
-            endpoint: mockito::server_url(),
-        };
+        let client = blocking::TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
 
-        let response = get_quotes(
-            &config,
-            vec!["AAPL".into(), "VXX190517P00016000".into()],
-            None,
-        );
+        let response = client.get_quotes(vec!["AAPL".into(), "VXX190517P00016000".into()], None);
         assert!(response.is_ok());
     }
+
+    #[tokio::test]
+    async fn test_get_quotes_async() {
+        let _m = mock(
+            "GET",
+            "/v1/markets/quotes?symbols=AAPL%2CVXX190517P00016000&greeks=false",
+        )
+        .with_status(200)
+        .with_body(include_str!("test_requests/get_quotes.json"))
+        .create();
+
+        let client = TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        let response = client
+            .get_quotes(vec!["AAPL".into(), "VXX190517P00016000".into()], None)
+            .await;
+        assert!(response.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_get_quotes_greeks() {
+        let _m = mock(
+            "GET",
+            "/v1/markets/quotes?symbols=VXX190517P00016000&greeks=true",
+        )
+        .with_status(200)
+        .with_body(include_str!("test_requests/get_quotes_greeks.json"))
+        .create();
+
+        let client = TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        let response = client
+            .get_quotes(vec!["VXX190517P00016000".into()], Some(true))
+            .await
+            .unwrap();
+        let greeks = response.quotes.quote[0].greeks.as_ref().unwrap();
+        assert_eq!(greeks.mid_iv, 1.0);
+        assert_eq!(greeks.smv_vol, 0.368);
+        assert_eq!(greeks.updated_at.to_string(), "2019-05-06 19:59:08");
+    }
+
+    #[tokio::test]
+    async fn test_get_quotes_bulk() {
+        let symbols: Vec<String> = (0..=super::QUOTE_CHUNK_SIZE)
+            .map(|i| format!("S{}", i))
+            .collect();
+        let _first = mock("POST", "/v1/markets/quotes")
+            .match_body(Matcher::Regex(
+                "^symbols=S0%2CS1%2C.*S999&greeks=false$".into(),
+            ))
+            .with_status(200)
+            .with_body(include_str!("test_requests/get_quotes.json"))
+            .create();
+        let _second = mock("POST", "/v1/markets/quotes")
+            .match_body("symbols=S1000&greeks=false")
+            .with_status(200)
+            .with_body(include_str!("test_requests/get_quotes_unmatched.json"))
+            .create();
+
+        let client = TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        let response = client.get_quotes_bulk(symbols, None).await.unwrap();
+        let symbols: Vec<&str> = response
+            .quotes
+            .quote
+            .iter()
+            .map(|quote| quote.symbol.as_str())
+            .collect();
+        assert_eq!(symbols, vec!["AAPL", "VXX190517P00016000", "SPY"]);
+        assert_eq!(response.quotes.unmatched_symbols, vec!["XYZ1", "XYZ2"]);
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn test_get_quotes_unmatched_only() {
+        let _m = mock("GET", "/v1/markets/quotes?symbols=NOPE&greeks=false")
+            .with_status(200)
+            .with_body(r#"{"quotes":{"unmatched_symbols":{"symbol":"NOPE"}}}"#)
+            .create();
+
+        let client = blocking::TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        let response = client.get_quotes(vec!["NOPE".into()], None).unwrap();
+        assert!(response.quotes.quote.is_empty());
+        assert_eq!(response.quotes.unmatched_symbols, vec!["NOPE"]);
+    }
 }
@@ -2,11 +2,12 @@
 
 use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
 use chrono_tz::America::New_York;
-use eyre::{eyre, Result};
 use optimistic_derives::*;
 use serde::{Deserialize, Serialize};
 
-use crate::{build_request_get, TradierConfig};
+#[cfg(feature = "blocking")]
+use crate::blocking;
+use crate::{Result, TradierClient};
 
 #[optimistic_no_ceho]
 struct NaiveData {
@@ -101,50 +102,80 @@ struct Query {
     session_filter: Option<SessionFilter>,
 }
 
-pub fn get_time_and_sales(
-    config: &TradierConfig,
-    symbol: String,
-    interval: Option<String>,
-    start_utc: Option<DateTime<Utc>>,
-    end_utc: Option<DateTime<Utc>>,
-    session_filter: Option<SessionFilter>,
-) -> Result<HistorySeries> {
-    let start = start_utc.map(|dt| dt.with_timezone(&New_York).naive_local());
-    let end = end_utc.map(|dt| dt.with_timezone(&New_York).naive_local());
-
-    let start_str = start.map(|dt| dt.format("%Y-%m-%d %H:%M").to_string());
-    let end_str = end.map(|dt| dt.format("%Y-%m-%d %H:%M").to_string());
-
-    let query = Query {
-        symbol,
-        interval,
-        start: start_str,
-        end: end_str,
-        session_filter,
-    };
-
-    let request = build_request_get(config, "markets/timesales", None::<()>, Some(query.clone()));
-    log::debug!("Request: {:?}", request);
-    let response: Result<NaiveHistorySeries, reqwest::Error> = request.send()?.json();
-    log::debug!("Response: {:?}", response);
-
-    match response {
-        Ok(resp) => Ok(resp.into()),
-        Err(_) => {
-            let err = build_request_get(config, "markets/timesales", None::<()>, Some(query))
-                .send()?
-                .text()?;
-            Err(eyre!("{:?}", err))
+impl Query {
+    fn new(
+        symbol: String,
+        interval: Option<String>,
+        start_utc: Option<DateTime<Utc>>,
+        end_utc: Option<DateTime<Utc>>,
+        session_filter: Option<SessionFilter>,
+    ) -> Self {
+        let start = start_utc.map(|dt| dt.with_timezone(&New_York).naive_local());
+        let end = end_utc.map(|dt| dt.with_timezone(&New_York).naive_local());
+
+        let start_str = start.map(|dt| dt.format("%Y-%m-%d %H:%M").to_string());
+        let end_str = end.map(|dt| dt.format("%Y-%m-%d %H:%M").to_string());
+
+        Query {
+            symbol,
+            interval,
+            start: start_str,
+            end: end_str,
+            session_filter,
         }
     }
 }
 
+#[cfg(feature = "blocking")]
+impl blocking::TradierClient {
+    pub fn get_time_and_sales(
+        &self,
+        symbol: String,
+        interval: Option<String>,
+        start_utc: Option<DateTime<Utc>>,
+        end_utc: Option<DateTime<Utc>>,
+        session_filter: Option<SessionFilter>,
+    ) -> Result<HistorySeries> {
+        let query = Query::new(symbol, interval, start_utc, end_utc, session_filter);
+
+        let request = self.build_request_get("markets/timesales", None::<()>, Some(query));
+        log::debug!("Request: {:?}", request);
+        let response: Result<NaiveHistorySeries> = self.send(request);
+        log::debug!("Response: {:?}", response);
+
+        Ok(response?.into())
+    }
+}
+
+impl TradierClient {
+    pub async fn get_time_and_sales(
+        &self,
+        symbol: String,
+        interval: Option<String>,
+        start_utc: Option<DateTime<Utc>>,
+        end_utc: Option<DateTime<Utc>>,
+        session_filter: Option<SessionFilter>,
+    ) -> Result<HistorySeries> {
+        let query = Query::new(symbol, interval, start_utc, end_utc, session_filter);
+
+        let request = self.build_request_get("markets/timesales", None::<()>, Some(query));
+        log::debug!("Request: {:?}", request);
+        let response: Result<NaiveHistorySeries> = self.send(request).await;
+        log::debug!("Response: {:?}", response);
+
+        Ok(response?.into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use mockito::mock;
 
-    use crate::{market_data::get_time_and_sales::get_time_and_sales, TradierConfig};
+    #[cfg(feature = "blocking")]
+    use crate::blocking;
+    use crate::{TradierClient, TradierConfig};
 
+    #[cfg(feature = "blocking")]
     #[test]
     fn test_get_time_and_sales() {
         let _m = mock("GET", "/v1/markets/timesales?symbol=AAPL&interval=1min&start=2021-08-12+20%3A00&end=2021-08-13+20%3A00")
@@ -152,10 +183,7 @@ mod tests {
             .with_body(include_str!("test_requests/get_time_and_sales.json"))
             .create();
 
-        let config = TradierConfig {
-            token: "xxx".into(),
-            endpoint: mockito::server_url(),
-        };
+        let client = blocking::TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
         let start = chrono::DateTime::parse_from_str(
             "2021 Aug 13 00:00:00 +0000",
             "%Y %b %d %H:%M:%S%.3f %z",
@@ -168,8 +196,7 @@ mod tests {
         )
         .unwrap()
         .with_timezone(&chrono::Utc);
-        let response = get_time_and_sales(
-            &config,
+        let response = client.get_time_and_sales(
             "AAPL".into(),
             Some("1min".into()),
             Some(start),
@@ -178,4 +205,36 @@ mod tests {
         );
         assert!(response.is_ok());
     }
+
+    #[tokio::test]
+    async fn test_get_time_and_sales_async() {
+        let _m = mock("GET", "/v1/markets/timesales?symbol=AAPL&interval=1min&start=2021-08-12+20%3A00&end=2021-08-13+20%3A00")
+            .with_status(200)
+            .with_body(include_str!("test_requests/get_time_and_sales.json"))
+            .create();
+
+        let client = TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+        let start = chrono::DateTime::parse_from_str(
+            "2021 Aug 13 00:00:00 +0000",
+            "%Y %b %d %H:%M:%S%.3f %z",
+        )
+        .unwrap()
+        .with_timezone(&chrono::Utc);
+        let end = chrono::DateTime::parse_from_str(
+            "2021 Aug 14 00:00:00 +0000",
+            "%Y %b %d %H:%M:%S%.3f %z",
+        )
+        .unwrap()
+        .with_timezone(&chrono::Utc);
+        let response = client
+            .get_time_and_sales(
+                "AAPL".into(),
+                Some("1min".into()),
+                Some(start),
+                Some(end),
+                None,
+            )
+            .await;
+        assert!(response.is_ok());
+    }
 }
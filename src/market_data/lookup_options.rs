@@ -0,0 +1,123 @@
+use optimistic_derives::*;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "blocking")]
+use crate::blocking;
+use crate::{Result, TradierClient};
+
+/// Every OCC option symbol listed under one root, which may differ from the
+/// underlying for adjusted or non-standard contracts.
+#[optimistic_no_c]
+pub struct OptionRoot {
+    #[serde(alias = "rootSymbol")]
+    pub root_symbol: String,
+    pub options: Vec<String>,
+}
+
+#[optimistic_no_c]
+#[serde(untagged)]
+enum OptionRootEnum {
+    Unit(OptionRoot),
+    Vec(Vec<OptionRoot>),
+}
+
+#[optimistic_no_c]
+struct NaiveOptionSymbolsRoot {
+    symbols: Option<OptionRootEnum>,
+}
+
+#[optimistic_no_c]
+pub struct OptionSymbols {
+    pub symbols: Vec<OptionRoot>,
+}
+
+impl From<NaiveOptionSymbolsRoot> for OptionSymbols {
+    fn from(item: NaiveOptionSymbolsRoot) -> Self {
+        OptionSymbols {
+            symbols: match item.symbols {
+                Some(OptionRootEnum::Unit(root)) => vec![root],
+                Some(OptionRootEnum::Vec(roots)) => roots,
+                None => vec![],
+            },
+        }
+    }
+}
+
+impl OptionSymbols {
+    /// Option symbols across all roots.
+    pub fn options(&self) -> impl Iterator<Item = &str> {
+        self.symbols
+            .iter()
+            .flat_map(|root| root.options.iter().map(|option| option.as_str()))
+    }
+}
+
+#[optimistic_no_c]
+struct Query {
+    underlying: String,
+}
+
+#[cfg(feature = "blocking")]
+impl blocking::TradierClient {
+    pub fn lookup_options(&self, underlying: String) -> Result<OptionSymbols> {
+        let query = Query { underlying };
+
+        let request = self.build_request_get("markets/options/lookup", None::<()>, Some(query));
+        let response: NaiveOptionSymbolsRoot = self.send(request)?;
+
+        Ok(response.into())
+    }
+}
+
+impl TradierClient {
+    pub async fn lookup_options(&self, underlying: String) -> Result<OptionSymbols> {
+        let query = Query { underlying };
+
+        let request = self.build_request_get("markets/options/lookup", None::<()>, Some(query));
+        let response: NaiveOptionSymbolsRoot = self.send(request).await?;
+
+        Ok(response.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::mock;
+
+    #[cfg(feature = "blocking")]
+    use crate::blocking;
+    use crate::{TradierClient, TradierConfig};
+
+    #[tokio::test]
+    async fn test_lookup_options() {
+        let _m = mock("GET", "/v1/markets/options/lookup?underlying=GE")
+            .with_status(200)
+            .with_body(include_str!("test_requests/lookup_options.json"))
+            .create();
+
+        let client = TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        let response = client.lookup_options("GE".into()).await.unwrap();
+        let roots: Vec<&str> = response
+            .symbols
+            .iter()
+            .map(|root| root.root_symbol.as_str())
+            .collect();
+        assert_eq!(roots, vec!["GE", "GE1"]);
+        assert_eq!(response.options().count(), 5);
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn test_lookup_options_null() {
+        let _m = mock("GET", "/v1/markets/options/lookup?underlying=XYZ")
+            .with_status(200)
+            .with_body(r#"{"symbols":null}"#)
+            .create();
+
+        let client = blocking::TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        let response = client.lookup_options("XYZ".into()).unwrap();
+        assert!(response.symbols.is_empty());
+    }
+}
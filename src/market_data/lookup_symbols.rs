@@ -0,0 +1,175 @@
+#![allow(non_camel_case_types)]
+
+use optimistic_derives::*;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "blocking")]
+use crate::blocking;
+use crate::{market_data::get_quotes::QuoteType, Result, TradierClient};
+
+#[optimistic_no_c]
+pub struct Security {
+    pub symbol: String,
+    pub exchange: String,
+    #[serde(alias = "type")]
+    pub security_type: QuoteType,
+    pub description: Option<String>,
+}
+
+#[optimistic_no_c]
+pub struct Securities {
+    pub security: Vec<Security>,
+}
+
+#[optimistic_no_c]
+pub(crate) struct SingleSecurity {
+    pub(crate) security: Security,
+}
+
+#[optimistic_no_c]
+pub struct SecuritiesRoot {
+    pub securities: Securities,
+}
+
+#[optimistic_no_c]
+pub(crate) struct SingleSecuritiesRoot {
+    pub(crate) securities: Option<SingleSecurity>,
+}
+
+#[optimistic_no_c]
+#[serde(untagged)]
+pub(crate) enum SecuritiesEnum {
+    Unit(SingleSecuritiesRoot),
+    Vec(SecuritiesRoot),
+}
+
+impl From<SecuritiesEnum> for SecuritiesRoot {
+    fn from(item: SecuritiesEnum) -> SecuritiesRoot {
+        match item {
+            SecuritiesEnum::Unit(unit) => SecuritiesRoot {
+                securities: Securities {
+                    security: match unit.securities {
+                        Some(sec) => vec![sec.security],
+                        None => vec![],
+                    },
+                },
+            },
+            SecuritiesEnum::Vec(root) => root,
+        }
+    }
+}
+
+#[optimistic_no_c]
+struct Query {
+    q: String,
+    exchanges: Option<String>,
+    types: Option<String>,
+}
+
+impl Query {
+    fn new(q: String, exchanges: Option<Vec<String>>, types: Option<Vec<QuoteType>>) -> Self {
+        Query {
+            q,
+            exchanges: exchanges.map(|exchanges| exchanges.join(",")),
+            types: types.map(|types| {
+                types
+                    .iter()
+                    .map(|t| t.to_string())
+                    .collect::<Vec<_>>()
+                    .join(",")
+            }),
+        }
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl blocking::TradierClient {
+    /// `markets/lookup`: symbols starting with `q`, optionally limited to
+    /// exchange codes like `Q` or `N` and to security types.
+    pub fn lookup_symbols(
+        &self,
+        q: String,
+        exchanges: Option<Vec<String>>,
+        types: Option<Vec<QuoteType>>,
+    ) -> Result<SecuritiesRoot> {
+        let query = Query::new(q, exchanges, types);
+
+        let request = self.build_request_get("markets/lookup", None::<()>, Some(query));
+        let response: SecuritiesEnum = self.send(request)?;
+
+        Ok(response.into())
+    }
+}
+
+impl TradierClient {
+    /// `markets/lookup`: symbols starting with `q`, optionally limited to
+    /// exchange codes like `Q` or `N` and to security types.
+    pub async fn lookup_symbols(
+        &self,
+        q: String,
+        exchanges: Option<Vec<String>>,
+        types: Option<Vec<QuoteType>>,
+    ) -> Result<SecuritiesRoot> {
+        let query = Query::new(q, exchanges, types);
+
+        let request = self.build_request_get("markets/lookup", None::<()>, Some(query));
+        let response: SecuritiesEnum = self.send(request).await?;
+
+        Ok(response.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::mock;
+
+    #[cfg(feature = "blocking")]
+    use crate::blocking;
+    use crate::{market_data::get_quotes::QuoteType, TradierClient, TradierConfig};
+
+    #[tokio::test]
+    async fn test_lookup_symbols() {
+        let _m = mock(
+            "GET",
+            "/v1/markets/lookup?q=goog&exchanges=Q%2CN&types=stock%2Cetf",
+        )
+        .with_status(200)
+        .with_body(include_str!("test_requests/lookup_symbols.json"))
+        .create();
+
+        let client = TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        let response = client
+            .lookup_symbols(
+                "goog".into(),
+                Some(vec!["Q".into(), "N".into()]),
+                Some(vec![QuoteType::stock, QuoteType::etf]),
+            )
+            .await
+            .unwrap();
+        let securities = response.securities.security;
+        assert_eq!(securities.len(), 2);
+        assert_eq!(securities[1].symbol, "GOOGL");
+        assert_eq!(securities[1].security_type, QuoteType::stock);
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn test_lookup_symbols_single_and_null() {
+        let client = blocking::TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        let _m = mock("GET", "/v1/markets/lookup?q=spy")
+            .with_status(200)
+            .with_body(include_str!("test_requests/lookup_symbols_single.json"))
+            .create();
+        let response = client.lookup_symbols("spy".into(), None, None).unwrap();
+        assert_eq!(response.securities.security[0].symbol, "SPY");
+
+        let _m = mock("GET", "/v1/markets/lookup?q=zzzz")
+            .with_status(200)
+            .with_body(r#"{"securities":null}"#)
+            .create();
+        let response = client.lookup_symbols("zzzz".into(), None, None).unwrap();
+        assert!(response.securities.security.is_empty());
+    }
+}
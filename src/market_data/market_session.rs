@@ -0,0 +1,207 @@
+use chrono::{DateTime, Datelike, NaiveDate, NaiveTime, TimeZone, Utc};
+use chrono_tz::America::New_York;
+use optimistic_derives::*;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "blocking")]
+use crate::blocking;
+use crate::{
+    market_data::get_calendar::{Day, DayStatus},
+    Result, TradierClient,
+};
+
+/// Regular sessions over the days of one or more `markets/calendar` months,
+/// for answering schedule questions without hard-coding holidays. Questions
+/// about times outside those days get `None`, or `false` from `is_open`.
+#[optimistic_no_c]
+pub struct MarketSession {
+    days: Vec<Day>,
+}
+
+impl MarketSession {
+    pub fn new(mut days: Vec<Day>) -> Self {
+        days.sort_by_key(|day| day.date);
+        days.dedup_by_key(|day| day.date);
+        MarketSession { days }
+    }
+
+    pub fn days(&self) -> &[Day] {
+        &self.days
+    }
+
+    fn day(&self, date: NaiveDate) -> Option<&Day> {
+        self.days
+            .binary_search_by_key(&date, |day| day.date)
+            .ok()
+            .map(|i| &self.days[i])
+    }
+
+    /// The regular session of an open day, converted from New York time to UTC.
+    fn regular_hours(day: &Day) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+        if day.status != DayStatus::open {
+            return None;
+        }
+        let hours = day.open?;
+        let at = |time: NaiveTime| {
+            New_York
+                .from_local_datetime(&day.date.and_time(time))
+                .unwrap()
+                .with_timezone(&Utc)
+        };
+        Some((at(hours.start), at(hours.end)))
+    }
+
+    /// Whether the regular session is open at `at`; pre and post market don't count.
+    pub fn is_open(&self, at: DateTime<Utc>) -> bool {
+        let date = at.with_timezone(&New_York).date_naive();
+        self.day(date)
+            .and_then(Self::regular_hours)
+            .is_some_and(|(open, close)| open <= at && at < close)
+    }
+
+    /// Whether the regular session is open right now.
+    pub fn is_open_now(&self) -> bool {
+        self.is_open(Utc::now())
+    }
+
+    /// The first regular session open strictly after `after`.
+    pub fn next_open(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.days
+            .iter()
+            .filter_map(Self::regular_hours)
+            .map(|(open, _)| open)
+            .find(|open| *open > after)
+    }
+
+    /// The first regular session close strictly after `after`.
+    pub fn next_close(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.days
+            .iter()
+            .filter_map(Self::regular_hours)
+            .map(|(_, close)| close)
+            .find(|close| *close > after)
+    }
+
+    /// An open day whose regular session ends before 16:00 New York time.
+    pub fn is_half_day(&self, date: NaiveDate) -> Option<bool> {
+        let day = self.day(date)?;
+        let full_day_close = NaiveTime::from_hms_opt(16, 0, 0).unwrap();
+        Some(day.status == DayStatus::open && day.open.is_some_and(|h| h.end < full_day_close))
+    }
+}
+
+/// The month after `year`/`month`, so sessions near a month end can see the next open.
+fn next_month(year: i32, month: u32) -> (i32, u32) {
+    if month == 12 {
+        (year + 1, 1)
+    } else {
+        (year, month + 1)
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl blocking::TradierClient {
+    /// Builds a [`MarketSession`] from the calendars of `year`/`month` and the month after.
+    pub fn get_market_session(&self, year: i32, month: u32) -> Result<MarketSession> {
+        let (next_year, next_month) = next_month(year, month);
+        let mut days = self
+            .get_calendar(Some(month), Some(year))?
+            .calendar
+            .days
+            .day;
+        days.extend(
+            self.get_calendar(Some(next_month), Some(next_year))?
+                .calendar
+                .days
+                .day,
+        );
+        Ok(MarketSession::new(days))
+    }
+
+    /// [`get_market_session`](Self::get_market_session) for the current New York month.
+    pub fn get_current_market_session(&self) -> Result<MarketSession> {
+        let today = Utc::now().with_timezone(&New_York);
+        self.get_market_session(today.year(), today.month())
+    }
+}
+
+impl TradierClient {
+    /// Builds a [`MarketSession`] from the calendars of `year`/`month` and the month after.
+    pub async fn get_market_session(&self, year: i32, month: u32) -> Result<MarketSession> {
+        let (next_year, next_month) = next_month(year, month);
+        let mut days = self
+            .get_calendar(Some(month), Some(year))
+            .await?
+            .calendar
+            .days
+            .day;
+        days.extend(
+            self.get_calendar(Some(next_month), Some(next_year))
+                .await?
+                .calendar
+                .days
+                .day,
+        );
+        Ok(MarketSession::new(days))
+    }
+
+    /// [`get_market_session`](Self::get_market_session) for the current New York month.
+    pub async fn get_current_market_session(&self) -> Result<MarketSession> {
+        let today = Utc::now().with_timezone(&New_York);
+        self.get_market_session(today.year(), today.month()).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, TimeZone, Utc};
+    use mockito::mock;
+
+    use crate::{TradierClient, TradierConfig};
+
+    #[tokio::test]
+    async fn test_market_session() {
+        let _november = mock("GET", "/v1/markets/calendar?month=11&year=2019")
+            .with_status(200)
+            .with_body(include_str!("test_requests/get_calendar.json"))
+            .create();
+        let _december = mock("GET", "/v1/markets/calendar?month=12&year=2019")
+            .with_status(200)
+            .with_body(include_str!("test_requests/get_calendar_december.json"))
+            .create();
+
+        let client = TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        let session = client.get_market_session(2019, 11).await.unwrap();
+        let date = |d| NaiveDate::from_ymd_opt(2019, 11, d).unwrap();
+
+        // Wednesday 27th, 15:00 New York.
+        let before_thanksgiving = Utc.with_ymd_and_hms(2019, 11, 27, 20, 0, 0).unwrap();
+        assert!(session.is_open(before_thanksgiving));
+        assert_eq!(
+            session.next_close(before_thanksgiving),
+            Some(Utc.with_ymd_and_hms(2019, 11, 27, 21, 0, 0).unwrap())
+        );
+        // Thanksgiving is skipped, the half day after closes at 13:00.
+        assert_eq!(
+            session.next_open(before_thanksgiving),
+            Some(Utc.with_ymd_and_hms(2019, 11, 29, 14, 30, 0).unwrap())
+        );
+        assert!(!session.is_open(Utc.with_ymd_and_hms(2019, 11, 28, 16, 0, 0).unwrap()));
+        assert!(!session.is_open(Utc.with_ymd_and_hms(2019, 11, 29, 18, 30, 0).unwrap()));
+        assert_eq!(session.is_half_day(date(29)), Some(true));
+        assert_eq!(session.is_half_day(date(27)), Some(false));
+        assert_eq!(session.is_half_day(date(28)), Some(false));
+
+        // The month after is loaded too.
+        let end_of_month = Utc.with_ymd_and_hms(2019, 11, 30, 12, 0, 0).unwrap();
+        assert_eq!(
+            session.next_open(end_of_month),
+            Some(Utc.with_ymd_and_hms(2019, 12, 2, 14, 30, 0).unwrap())
+        );
+        assert_eq!(
+            session.is_half_day(NaiveDate::from_ymd_opt(2020, 1, 2).unwrap()),
+            None
+        );
+    }
+}
@@ -1,3 +1,29 @@
 pub mod get_time_and_sales;
 
 pub mod get_quotes;
+
+pub mod get_history;
+
+pub mod get_option_chains;
+
+pub mod get_option_expirations;
+
+pub mod get_option_strikes;
+
+pub mod get_clock;
+
+pub mod get_calendar;
+
+pub mod market_session;
+
+pub mod lookup_symbols;
+
+pub mod search_companies;
+
+pub mod lookup_options;
+
+pub mod get_etb;
+
+pub mod fundamentals;
+
+pub mod create_events_session;
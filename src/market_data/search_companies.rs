@@ -0,0 +1,71 @@
+use optimistic_derives::*;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "blocking")]
+use crate::blocking;
+use crate::{
+    market_data::lookup_symbols::{SecuritiesEnum, SecuritiesRoot},
+    Result, TradierClient,
+};
+
+#[optimistic_no_c]
+struct Query {
+    q: String,
+    indexes: Option<bool>,
+}
+
+#[cfg(feature = "blocking")]
+impl blocking::TradierClient {
+    /// `markets/search`: securities whose company name or description matches `q`.
+    pub fn search_companies(&self, q: String, indexes: Option<bool>) -> Result<SecuritiesRoot> {
+        let query = Query { q, indexes };
+
+        let request = self.build_request_get("markets/search", None::<()>, Some(query));
+        let response: SecuritiesEnum = self.send(request)?;
+
+        Ok(response.into())
+    }
+}
+
+impl TradierClient {
+    /// `markets/search`: securities whose company name or description matches `q`.
+    pub async fn search_companies(
+        &self,
+        q: String,
+        indexes: Option<bool>,
+    ) -> Result<SecuritiesRoot> {
+        let query = Query { q, indexes };
+
+        let request = self.build_request_get("markets/search", None::<()>, Some(query));
+        let response: SecuritiesEnum = self.send(request).await?;
+
+        Ok(response.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::mock;
+
+    use crate::{market_data::get_quotes::QuoteType, TradierClient, TradierConfig};
+
+    #[tokio::test]
+    async fn test_search_companies() {
+        let _m = mock("GET", "/v1/markets/search?q=alphabet&indexes=false")
+            .with_status(200)
+            .with_body(include_str!("test_requests/lookup_symbols.json"))
+            .create();
+
+        let client = TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        let response = client
+            .search_companies("alphabet".into(), Some(false))
+            .await
+            .unwrap();
+        assert_eq!(response.securities.security.len(), 2);
+        assert_eq!(
+            response.securities.security[0].security_type,
+            QuoteType::stock
+        );
+    }
+}
@@ -0,0 +1,425 @@
+//! The OAuth 2 authorization code flow, for apps acting on behalf of other
+//! Tradier users. Tokens live in a [`TokenStore`], so a client built with
+//! [`TradierClientBuilder::oauth`](crate::TradierClientBuilder::oauth) can
+//! refresh them before they expire and persist the result.
+
+#![allow(non_camel_case_types)]
+
+use std::sync::{Arc, Mutex};
+
+use ::config::ConfigError;
+use chrono::{DateTime, Duration, Utc};
+use optimistic_derives::*;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "blocking")]
+use crate::blocking;
+use crate::{endpoint, Result, TradierClient, TradierConfig, TradierError};
+
+/// Tokens this close to expiry are refreshed before use.
+const EXPIRY_MARGIN: i64 = 60;
+
+#[optimistic]
+pub enum Scope {
+    read,
+    write,
+    market,
+    trade,
+    stream,
+}
+
+impl std::fmt::Display for Scope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[optimistic_no_c]
+pub struct Token {
+    pub access_token: String,
+    pub refresh_token: Option<String>,
+    pub scope: Option<String>,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl Token {
+    /// Whether the token is expired, or will be within a minute of `at`.
+    pub fn is_expired(&self, at: DateTime<Utc>) -> bool {
+        self.expires_at
+            .is_some_and(|expires_at| expires_at <= at + Duration::seconds(EXPIRY_MARGIN))
+    }
+}
+
+#[optimistic_no_c]
+struct NaiveToken {
+    access_token: String,
+    refresh_token: Option<String>,
+    scope: Option<String>,
+    expires_in: Option<i64>,
+}
+
+impl NaiveToken {
+    /// A refresh response may leave out the refresh token, in which case the
+    /// previous one stays valid.
+    fn into_token(self, previous_refresh_token: Option<String>) -> Token {
+        Token {
+            access_token: self.access_token,
+            refresh_token: self.refresh_token.or(previous_refresh_token),
+            scope: self.scope,
+            expires_at: self
+                .expires_in
+                .map(|seconds| Utc::now() + Duration::seconds(seconds)),
+        }
+    }
+}
+
+/// Where a client keeps its user's token. Implement it over a database or
+/// keychain to share tokens between processes; `save` is called after every
+/// exchange and refresh.
+pub trait TokenStore: Send + Sync {
+    fn load(&self) -> Result<Option<Token>>;
+    fn save(&self, token: &Token) -> Result<()>;
+}
+
+#[derive(Debug, Default)]
+pub struct MemoryTokenStore {
+    token: Mutex<Option<Token>>,
+}
+
+impl MemoryTokenStore {
+    pub fn new(token: Option<Token>) -> Self {
+        MemoryTokenStore {
+            token: Mutex::new(token),
+        }
+    }
+}
+
+impl TokenStore for MemoryTokenStore {
+    fn load(&self) -> Result<Option<Token>> {
+        Ok(self.token.lock().unwrap().clone())
+    }
+
+    fn save(&self, token: &Token) -> Result<()> {
+        *self.token.lock().unwrap() = Some(token.clone());
+        Ok(())
+    }
+}
+
+/// The app's credentials and the store holding its user's token. Clones
+/// share the store and take turns refreshing it.
+#[derive(Clone)]
+pub struct OAuth {
+    pub client_id: String,
+    pub client_secret: String,
+    pub store: Arc<dyn TokenStore>,
+    refresh_lock: Arc<tokio::sync::Mutex<()>>,
+}
+
+impl std::fmt::Debug for OAuth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OAuth")
+            .field("client_id", &self.client_id)
+            .field("client_secret", &"<redacted>")
+            .finish_non_exhaustive()
+    }
+}
+
+impl OAuth {
+    pub fn new(
+        client_id: impl Into<String>,
+        client_secret: impl Into<String>,
+        store: impl TokenStore + 'static,
+    ) -> Self {
+        OAuth {
+            client_id: client_id.into(),
+            client_secret: client_secret.into(),
+            store: Arc::new(store),
+            refresh_lock: Arc::default(),
+        }
+    }
+
+    /// Where to send the user to grant `scopes`. Tradier redirects back to
+    /// the app's configured callback with `code` and the same `state`.
+    pub fn authorize_url(
+        &self,
+        config: &TradierConfig,
+        scopes: &[Scope],
+        state: &str,
+    ) -> Result<String> {
+        let url = endpoint(config, "oauth/authorize");
+        let scope = scopes
+            .iter()
+            .map(Scope::to_string)
+            .collect::<Vec<_>>()
+            .join(",");
+        let params = [
+            ("client_id", self.client_id.as_str()),
+            ("scope", &scope),
+            ("state", state),
+        ];
+        let url = reqwest::Url::parse_with_params(&url, &params)
+            .map_err(|e| ConfigError::Message(format!("invalid endpoint {}: {}", url, e)))?;
+        Ok(url.to_string())
+    }
+
+    fn stored_token(&self) -> Result<Stored> {
+        match self.store.load()? {
+            None => Err(TradierError::OAuth(
+                "no token stored, exchange an authorization code first".into(),
+            )),
+            Some(token) if !token.is_expired(Utc::now()) => Ok(Stored::Fresh(token)),
+            Some(token) => match token.refresh_token {
+                Some(refresh_token) => Ok(Stored::Expired(refresh_token)),
+                None => Err(TradierError::OAuth(
+                    "token expired and there's no refresh token".into(),
+                )),
+            },
+        }
+    }
+}
+
+enum Stored {
+    Fresh(Token),
+    /// Holds the refresh token to use.
+    Expired(String),
+}
+
+#[optimistic_no_c]
+struct CodeBody {
+    grant_type: String,
+    code: String,
+}
+
+#[optimistic_no_c]
+struct RefreshBody {
+    grant_type: String,
+    refresh_token: String,
+}
+
+fn code_body(code: &str) -> CodeBody {
+    CodeBody {
+        grant_type: "authorization_code".into(),
+        code: code.into(),
+    }
+}
+
+fn refresh_body(refresh_token: &str) -> RefreshBody {
+    RefreshBody {
+        grant_type: "refresh_token".into(),
+        refresh_token: refresh_token.into(),
+    }
+}
+
+fn stored_refresh_token(oauth: &OAuth) -> Result<String> {
+    oauth
+        .store
+        .load()?
+        .and_then(|token| token.refresh_token)
+        .ok_or_else(|| TradierError::OAuth("no refresh token stored".into()))
+}
+
+fn not_configured() -> TradierError {
+    TradierError::OAuth("client was built without OAuth credentials".into())
+}
+
+#[cfg(feature = "blocking")]
+impl blocking::TradierClient {
+    pub fn authorize_url(&self, scopes: &[Scope], state: &str) -> Result<String> {
+        let oauth = self.oauth().ok_or_else(not_configured)?;
+        oauth.authorize_url(self.config(), scopes, state)
+    }
+
+    /// Trades the `code` from the authorize redirect for a token and stores it.
+    pub fn exchange_code(&self, code: &str) -> Result<Token> {
+        let oauth = self.oauth().ok_or_else(not_configured)?;
+        let request = self.build_request_token(oauth, "oauth/accesstoken", code_body(code));
+        let token = self.execute::<NaiveToken>(request)?.into_token(None);
+        oauth.store.save(&token)?;
+        Ok(token)
+    }
+
+    /// Refreshes the stored token now, whether or not it has expired.
+    pub fn refresh_token(&self) -> Result<Token> {
+        let oauth = self.oauth().ok_or_else(not_configured)?;
+        let _refreshing = oauth.refresh_lock.blocking_lock();
+        self.refresh(oauth, stored_refresh_token(oauth)?)
+    }
+
+    fn refresh(&self, oauth: &OAuth, refresh_token: String) -> Result<Token> {
+        let body = refresh_body(&refresh_token);
+        let request = self.build_request_token(oauth, "oauth/refreshtoken", body);
+        let token = self
+            .execute::<NaiveToken>(request)?
+            .into_token(Some(refresh_token));
+        oauth.store.save(&token)?;
+        Ok(token)
+    }
+
+    pub(crate) fn access_token(&self, oauth: &OAuth) -> Result<String> {
+        if let Stored::Fresh(token) = oauth.stored_token()? {
+            return Ok(token.access_token);
+        }
+        let _refreshing = oauth.refresh_lock.blocking_lock();
+        // Another request may have refreshed it while this one waited.
+        match oauth.stored_token()? {
+            Stored::Fresh(token) => Ok(token.access_token),
+            Stored::Expired(refresh_token) => Ok(self.refresh(oauth, refresh_token)?.access_token),
+        }
+    }
+}
+
+impl TradierClient {
+    pub fn authorize_url(&self, scopes: &[Scope], state: &str) -> Result<String> {
+        let oauth = self.oauth().ok_or_else(not_configured)?;
+        oauth.authorize_url(self.config(), scopes, state)
+    }
+
+    /// Trades the `code` from the authorize redirect for a token and stores it.
+    pub async fn exchange_code(&self, code: &str) -> Result<Token> {
+        let oauth = self.oauth().ok_or_else(not_configured)?;
+        let request = self.build_request_token(oauth, "oauth/accesstoken", code_body(code));
+        let token = self.execute::<NaiveToken>(request).await?.into_token(None);
+        oauth.store.save(&token)?;
+        Ok(token)
+    }
+
+    /// Refreshes the stored token now, whether or not it has expired.
+    pub async fn refresh_token(&self) -> Result<Token> {
+        let oauth = self.oauth().ok_or_else(not_configured)?;
+        let _refreshing = oauth.refresh_lock.lock().await;
+        self.refresh(oauth, stored_refresh_token(oauth)?).await
+    }
+
+    async fn refresh(&self, oauth: &OAuth, refresh_token: String) -> Result<Token> {
+        let body = refresh_body(&refresh_token);
+        let request = self.build_request_token(oauth, "oauth/refreshtoken", body);
+        let token = self
+            .execute::<NaiveToken>(request)
+            .await?
+            .into_token(Some(refresh_token));
+        oauth.store.save(&token)?;
+        Ok(token)
+    }
+
+    pub(crate) async fn access_token(&self, oauth: &OAuth) -> Result<String> {
+        if let Stored::Fresh(token) = oauth.stored_token()? {
+            return Ok(token.access_token);
+        }
+        let _refreshing = oauth.refresh_lock.lock().await;
+        // Another request may have refreshed it while this one waited.
+        match oauth.stored_token()? {
+            Stored::Fresh(token) => Ok(token.access_token),
+            Stored::Expired(refresh_token) => {
+                Ok(self.refresh(oauth, refresh_token).await?.access_token)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::{Duration, Utc};
+    use mockito::{mock, Matcher};
+
+    use crate::{
+        oauth::{MemoryTokenStore, OAuth, Scope, Token, TokenStore},
+        TradierClient, TradierConfig, TradierError,
+    };
+
+    fn client(store: Arc<MemoryTokenStore>) -> TradierClient {
+        let oauth = OAuth {
+            client_id: "app".into(),
+            client_secret: "secret".into(),
+            store,
+            refresh_lock: Arc::default(),
+        };
+        TradierClient::builder(TradierConfig::new("", mockito::server_url()))
+            .oauth(oauth)
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_exchange_code() {
+        let _m = mock("POST", "/v1/oauth/accesstoken")
+            .match_header("authorization", "Basic YXBwOnNlY3JldA==")
+            .match_body("grant_type=authorization_code&code=abc123")
+            .with_status(200)
+            .with_body(include_str!("test_requests/post_accesstoken.json"))
+            .create();
+
+        let store = Arc::new(MemoryTokenStore::default());
+        let client = client(store.clone());
+
+        let url = client
+            .authorize_url(&[Scope::read, Scope::trade], "xyz")
+            .unwrap();
+        assert_eq!(
+            url,
+            format!(
+                "{}/v1/oauth/authorize?client_id=app&scope=read%2Ctrade&state=xyz",
+                mockito::server_url()
+            )
+        );
+
+        let token = client.exchange_code("abc123").await.unwrap();
+        assert_eq!(token.access_token, "first-token");
+        assert!(!token.is_expired(Utc::now()));
+        assert_eq!(store.load().unwrap(), Some(token));
+
+        let debug = format!("{:?}", client);
+        assert!(debug.contains("client_id: \"app\""));
+        assert!(!debug.contains("\"secret\""));
+        assert!(!debug.contains("first-token"));
+    }
+
+    #[tokio::test]
+    async fn test_refreshes_expired_token() {
+        let refresh = mock("POST", "/v1/oauth/refreshtoken")
+            .match_body(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("grant_type".into(), "refresh_token".into()),
+                Matcher::UrlEncoded("refresh_token".into(), "refresh-me".into()),
+            ]))
+            .with_status(200)
+            .with_body(include_str!("test_requests/post_refreshtoken.json"))
+            .expect(1)
+            .create();
+        let clock = mock("GET", "/v1/markets/clock")
+            .match_header("authorization", "Bearer second-token")
+            .with_status(200)
+            .with_body(include_str!("../market_data/test_requests/get_clock.json"))
+            .expect(2)
+            .create();
+
+        let store = Arc::new(MemoryTokenStore::new(Some(Token {
+            access_token: "expired-token".into(),
+            refresh_token: Some("refresh-me".into()),
+            scope: None,
+            expires_at: Some(Utc::now() - Duration::hours(1)),
+        })));
+        let client = client(store.clone());
+
+        // Both requests find the token expired, but only one refreshes it.
+        let clone = client.clone();
+        let (first, second) = tokio::join!(client.get_clock(), clone.get_clock());
+        assert!(first.is_ok() && second.is_ok());
+        refresh.assert();
+        clock.assert();
+
+        let token = store.load().unwrap().unwrap();
+        assert_eq!(token.access_token, "second-token");
+        // The refresh response didn't rotate the refresh token.
+        assert_eq!(token.refresh_token.as_deref(), Some("refresh-me"));
+    }
+
+    #[tokio::test]
+    async fn test_no_token_stored() {
+        let client = client(Arc::new(MemoryTokenStore::default()));
+        assert!(matches!(
+            client.get_clock().await,
+            Err(TradierError::OAuth(_))
+        ));
+    }
+}
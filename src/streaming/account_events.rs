@@ -0,0 +1,214 @@
+#![allow(non_camel_case_types)]
+
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use optimistic_derives::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    blocking,
+    streaming::socket::{Reconnecting, Socket},
+    OrderStatus, OrderType, Result, TradierError,
+};
+
+pub const ACCOUNT_EVENTS_URL: &str = "wss://ws.tradier.com/v1/accounts/events";
+
+/// A change to an order, as pushed by the account events stream.
+#[optimistic_no_ceho]
+pub struct OrderEvent {
+    pub id: u64,
+    pub account: String,
+    pub status: OrderStatus,
+    #[serde(alias = "type")]
+    pub order_type: Option<OrderType>,
+    pub price: Option<f64>,
+    pub stop_price: Option<f64>,
+    pub avg_fill_price: f64,
+    #[serde(alias = "executed_quantity")]
+    pub exec_quantity: f64,
+    pub last_fill_quantity: f64,
+    pub remaining_quantity: f64,
+    pub transaction_date: DateTime<Utc>,
+    pub create_date: DateTime<Utc>,
+}
+
+impl OrderEvent {
+    /// Whether the order can't change any more.
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self.status,
+            OrderStatus::filled
+                | OrderStatus::canceled
+                | OrderStatus::expired
+                | OrderStatus::rejected
+                | OrderStatus::error
+        )
+    }
+}
+
+#[optimistic_no_ceho]
+#[serde(tag = "event")]
+enum AccountEvent {
+    order(OrderEvent),
+    heartbeat,
+}
+
+/// Decodes a chunk of newline separated events, dropping heartbeats.
+fn parse_events(chunk: &str) -> Vec<Result<OrderEvent>> {
+    chunk
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .filter_map(|line| match serde_json::from_str(line) {
+            Ok(AccountEvent::order(event)) => Some(Ok(event)),
+            Ok(AccountEvent::heartbeat) => None,
+            Err(source) => Some(Err(TradierError::Decode {
+                source,
+                body: line.to_string(),
+            })),
+        })
+        .collect()
+}
+
+/// Order events for every account the token can see, see
+/// [`stream_account_events`](blocking::TradierClient::stream_account_events).
+///
+/// Tradier sends heartbeats while the stream is idle. If nothing at all
+/// arrives within `heartbeat_timeout` the connection is treated as dead and
+/// reopened with a new session, like any other drop.
+#[derive(Debug)]
+pub struct AccountStream {
+    client: blocking::TradierClient,
+    exclude_accounts: Vec<String>,
+    url: String,
+    heartbeat_timeout: Duration,
+    stream: Reconnecting<OrderEvent>,
+}
+
+impl AccountStream {
+    pub fn new(client: blocking::TradierClient) -> Self {
+        AccountStream {
+            client,
+            exclude_accounts: vec![],
+            url: ACCOUNT_EVENTS_URL.to_string(),
+            heartbeat_timeout: Duration::from_secs(60),
+            stream: Reconnecting::new("account stream"),
+        }
+    }
+
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.url = url.into();
+        self
+    }
+
+    pub fn exclude_accounts(mut self, exclude_accounts: Vec<String>) -> Self {
+        self.exclude_accounts = exclude_accounts;
+        self
+    }
+
+    /// Defaults to a minute.
+    pub fn heartbeat_timeout(mut self, heartbeat_timeout: Duration) -> Self {
+        self.heartbeat_timeout = heartbeat_timeout;
+        self
+    }
+
+    /// `None` reconnects forever. Defaults to 5.
+    pub fn max_reconnects(mut self, max_reconnects: Option<u32>) -> Self {
+        self.stream.retry.max_reconnects = max_reconnects;
+        self
+    }
+
+    /// Defaults to one second.
+    pub fn reconnect_delay(mut self, delay: Duration) -> Self {
+        self.stream.retry.delay = delay;
+        self
+    }
+}
+
+impl Iterator for AccountStream {
+    type Item = Result<OrderEvent>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (client, exclude_accounts, url) = (&self.client, &self.exclude_accounts, &self.url);
+        let heartbeat_timeout = self.heartbeat_timeout;
+        let connect = || {
+            let session = client.create_account_events_session()?.stream;
+            let payload = serde_json::json!({
+                "events": ["order"],
+                "sessionid": session.sessionid,
+                "excludeAccounts": exclude_accounts,
+            });
+            Socket::connect(url, payload.to_string(), Some(heartbeat_timeout))
+        };
+        self.stream.next(connect, parse_events)
+    }
+}
+
+impl blocking::TradierClient {
+    /// Streams order events, as an alternative to polling
+    /// [`get_orders`](Self::get_orders). Tradier only offers streaming on the
+    /// live endpoint.
+    pub fn stream_account_events(&self) -> AccountStream {
+        AccountStream::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use mockito::mock;
+
+    use crate::{blocking, streaming::test_server, OrderStatus, TradierConfig};
+
+    #[test]
+    fn test_account_stream_heartbeats() {
+        let (url, server) = test_server::websocket(vec![
+            include_str!("test_requests/account_events.txt"),
+            include_str!("test_requests/account_filled.txt"),
+        ]);
+
+        let session = mock("POST", "/v1/accounts/events/session")
+            .with_status(200)
+            .with_body(include_str!(
+                "../account/test_requests/create_events_session.json"
+            ))
+            .expect(2)
+            .create();
+
+        let client = blocking::TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+        let events: Vec<_> = client
+            .stream_account_events()
+            .url(url)
+            .exclude_accounts(vec!["VA000001".into()])
+            .heartbeat_timeout(Duration::from_millis(200))
+            .reconnect_delay(Duration::from_millis(10))
+            .take(2)
+            .map(|event| event.unwrap())
+            .collect();
+
+        assert_eq!(events[0].id, 2799541);
+        assert_eq!(events[0].status, OrderStatus::partially_filled);
+        assert_eq!(events[0].exec_quantity, 50.0);
+        assert!(!events[0].is_final());
+        assert_eq!(events[1].status, OrderStatus::filled);
+        assert_eq!(events[1].avg_fill_price, 28.21);
+        assert!(events[1].is_final());
+
+        let subscriptions = server.join().unwrap();
+        assert_eq!(subscriptions.len(), 2);
+        for subscription in subscriptions {
+            assert_eq!(subscription["events"], serde_json::json!(["order"]));
+            assert_eq!(
+                subscription["sessionid"],
+                "b3a3f2cc-2f3a-4a2c-9d5f-44cb1bd1c0f1"
+            );
+            assert_eq!(
+                subscription["excludeAccounts"],
+                serde_json::json!(["VA000001"])
+            );
+        }
+        session.assert();
+    }
+}
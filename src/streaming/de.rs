@@ -0,0 +1,60 @@
+//! Stream payloads send most numbers as strings and times as epoch
+//! milliseconds, sometimes quoted and sometimes not.
+
+use std::{fmt::Display, str::FromStr};
+
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{de::Error, Deserialize, Deserializer};
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum NumberOrString<T> {
+    Number(T),
+    String(String),
+}
+
+fn parse<T, E>(value: NumberOrString<T>) -> Result<Option<T>, E>
+where
+    T: FromStr,
+    T::Err: Display,
+    E: Error,
+{
+    match value {
+        NumberOrString::Number(number) => Ok(Some(number)),
+        NumberOrString::String(text) if text.trim().is_empty() => Ok(None),
+        NumberOrString::String(text) => text.trim().parse().map(Some).map_err(E::custom),
+    }
+}
+
+pub(crate) fn number<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + FromStr,
+    T::Err: Display,
+{
+    parse(NumberOrString::deserialize(deserializer)?)?
+        .ok_or_else(|| D::Error::custom("expected a number, got an empty string"))
+}
+
+/// Missing, `null` and `""` all become `None`.
+pub(crate) fn optional_number<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + FromStr,
+    T::Err: Display,
+{
+    match Option::<NumberOrString<T>>::deserialize(deserializer)? {
+        Some(value) => parse(value),
+        None => Ok(None),
+    }
+}
+
+pub(crate) fn millis<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let millis: i64 = number(deserializer)?;
+    Utc.timestamp_millis_opt(millis)
+        .single()
+        .ok_or_else(|| D::Error::custom(format!("invalid timestamp {}", millis)))
+}
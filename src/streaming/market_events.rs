@@ -0,0 +1,562 @@
+#![allow(non_camel_case_types)]
+
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use optimistic_derives::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    blocking,
+    streaming::{
+        de,
+        socket::{Reconnecting, Socket},
+    },
+    Result, TradierError,
+};
+
+pub const MARKET_EVENTS_URL: &str = "wss://ws.tradier.com/v1/markets/events";
+
+/// How market events are delivered. Both give the same events; `http` reads
+/// a long-lived chunked response, for networks that block WebSockets.
+#[optimistic]
+#[derive(Default)]
+pub enum Transport {
+    #[default]
+    websocket,
+    http,
+}
+
+#[optimistic]
+pub enum Filter {
+    trade,
+    quote,
+    summary,
+    timesale,
+    tradex,
+}
+
+#[optimistic_no_ceho]
+pub struct QuoteEvent {
+    pub symbol: String,
+    #[serde(deserialize_with = "de::number")]
+    pub bid: f64,
+    #[serde(deserialize_with = "de::number")]
+    pub bidsz: i64,
+    pub bidexch: String,
+    #[serde(deserialize_with = "de::millis")]
+    pub biddate: DateTime<Utc>,
+    #[serde(deserialize_with = "de::number")]
+    pub ask: f64,
+    #[serde(deserialize_with = "de::number")]
+    pub asksz: i64,
+    pub askexch: String,
+    #[serde(deserialize_with = "de::millis")]
+    pub askdate: DateTime<Utc>,
+}
+
+/// Used for both `trade` and `tradex` events. `cvol` is the day's cumulative volume.
+#[optimistic_no_ceho]
+pub struct TradeEvent {
+    pub symbol: String,
+    pub exch: String,
+    #[serde(deserialize_with = "de::number")]
+    pub price: f64,
+    #[serde(deserialize_with = "de::number")]
+    pub size: i64,
+    #[serde(deserialize_with = "de::number")]
+    pub cvol: i64,
+    #[serde(deserialize_with = "de::millis")]
+    pub date: DateTime<Utc>,
+    #[serde(deserialize_with = "de::number")]
+    pub last: f64,
+}
+
+#[optimistic_no_ceho]
+pub struct SummaryEvent {
+    pub symbol: String,
+    #[serde(default, deserialize_with = "de::optional_number")]
+    pub open: Option<f64>,
+    #[serde(default, deserialize_with = "de::optional_number")]
+    pub high: Option<f64>,
+    #[serde(default, deserialize_with = "de::optional_number")]
+    pub low: Option<f64>,
+    #[serde(default, alias = "prevClose", deserialize_with = "de::optional_number")]
+    pub prev_close: Option<f64>,
+    #[serde(default, deserialize_with = "de::optional_number")]
+    pub close: Option<f64>,
+}
+
+#[optimistic_no_ceho]
+pub struct TimeSaleEvent {
+    pub symbol: String,
+    pub exch: String,
+    #[serde(deserialize_with = "de::number")]
+    pub bid: f64,
+    #[serde(deserialize_with = "de::number")]
+    pub ask: f64,
+    #[serde(deserialize_with = "de::number")]
+    pub last: f64,
+    #[serde(deserialize_with = "de::number")]
+    pub size: i64,
+    #[serde(deserialize_with = "de::millis")]
+    pub date: DateTime<Utc>,
+    #[serde(deserialize_with = "de::number")]
+    pub seq: i64,
+    pub flag: String,
+    pub cancel: bool,
+    pub correction: bool,
+    pub session: String,
+}
+
+#[optimistic_no_ceho]
+#[serde(tag = "type")]
+pub enum MarketEvent {
+    quote(QuoteEvent),
+    trade(TradeEvent),
+    summary(SummaryEvent),
+    timesale(TimeSaleEvent),
+    tradex(TradeEvent),
+}
+
+impl MarketEvent {
+    pub fn symbol(&self) -> &str {
+        match self {
+            MarketEvent::quote(event) => &event.symbol,
+            MarketEvent::trade(event) | MarketEvent::tradex(event) => &event.symbol,
+            MarketEvent::summary(event) => &event.symbol,
+            MarketEvent::timesale(event) => &event.symbol,
+        }
+    }
+}
+
+/// What to stream. An empty `filter` streams every event type.
+#[optimistic_no_c]
+pub struct Subscription {
+    pub symbols: Vec<String>,
+    pub filter: Vec<Filter>,
+    /// Drop trades Tradier flags as out of sequence or cancelled.
+    pub valid_only: bool,
+}
+
+impl Subscription {
+    pub fn new(symbols: Vec<String>) -> Self {
+        Subscription {
+            symbols,
+            filter: vec![],
+            valid_only: true,
+        }
+    }
+
+    pub fn filter(mut self, filter: Vec<Filter>) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    pub fn valid_only(mut self, valid_only: bool) -> Self {
+        self.valid_only = valid_only;
+        self
+    }
+
+    pub(crate) fn payload(&self, sessionid: &str) -> serde_json::Value {
+        let mut payload = serde_json::json!({
+            "symbols": self.symbols,
+            "sessionid": sessionid,
+            "linebreak": true,
+            "validOnly": self.valid_only,
+        });
+        if !self.filter.is_empty() {
+            payload["filter"] = serde_json::json!(self.filter);
+        }
+        payload
+    }
+
+    /// The same subscription as form fields, for the HTTP transport.
+    pub(crate) fn form(&self, sessionid: &str) -> Vec<(&'static str, String)> {
+        let mut form = vec![
+            ("sessionid", sessionid.to_string()),
+            ("symbols", self.symbols.join(",")),
+            ("linebreak", "true".to_string()),
+            ("validOnly", self.valid_only.to_string()),
+        ];
+        if !self.filter.is_empty() {
+            let filter: Vec<String> = self.filter.iter().map(|f| format!("{:?}", f)).collect();
+            form.push(("filter", filter.join(",")));
+        }
+        form
+    }
+}
+
+/// Decodes a chunk of one or more newline separated events.
+pub(crate) fn parse_events(chunk: &str) -> Vec<Result<MarketEvent>> {
+    chunk
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            serde_json::from_str(line).map_err(|source| TradierError::Decode {
+                source,
+                body: line.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Market events over a WebSocket or HTTP, see
+/// [`stream_market_events`](blocking::TradierClient::stream_market_events).
+///
+/// The connection is opened on the first call to `next`. When it drops, or
+/// nothing arrives within `idle_timeout`, a new session is created and the
+/// subscription is sent again. The iterator
+/// ends after `max_reconnects` reconnects in a row fail to deliver anything,
+/// yielding the last error if there was one.
+#[derive(Debug)]
+pub struct MarketStream {
+    client: blocking::TradierClient,
+    subscription: Subscription,
+    transport: Transport,
+    url: Option<String>,
+    idle_timeout: Duration,
+    stream: Reconnecting<MarketEvent>,
+}
+
+impl MarketStream {
+    pub fn new(client: blocking::TradierClient, subscription: Subscription) -> Self {
+        MarketStream {
+            client,
+            subscription,
+            transport: Transport::default(),
+            url: None,
+            idle_timeout: Duration::from_secs(60),
+            stream: Reconnecting::new("market stream"),
+        }
+    }
+
+    pub fn transport(mut self, transport: Transport) -> Self {
+        self.transport = transport;
+        self
+    }
+
+    /// Overrides where to connect. By default WebSockets go to
+    /// [`MARKET_EVENTS_URL`] and HTTP to the URL the session hands out.
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.url = Some(url.into());
+        self
+    }
+
+    /// How long the connection may stay silent before it's treated as dead.
+    /// Quiet symbols can go longer than the default minute between events,
+    /// so raise it when streaming those. Applies to both transports.
+    pub fn idle_timeout(mut self, idle_timeout: Duration) -> Self {
+        self.idle_timeout = idle_timeout;
+        self
+    }
+
+    /// `None` reconnects forever. Defaults to 5.
+    pub fn max_reconnects(mut self, max_reconnects: Option<u32>) -> Self {
+        self.stream.retry.max_reconnects = max_reconnects;
+        self
+    }
+
+    /// Defaults to one second.
+    pub fn reconnect_delay(mut self, delay: Duration) -> Self {
+        self.stream.retry.delay = delay;
+        self
+    }
+
+    pub fn subscription(&self) -> &Subscription {
+        &self.subscription
+    }
+}
+
+impl Iterator for MarketStream {
+    type Item = Result<MarketEvent>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (client, subscription, url) = (&self.client, &self.subscription, &self.url);
+        let (transport, idle_timeout) = (self.transport, self.idle_timeout);
+        let connect = || {
+            let session = client.create_market_events_session()?.stream;
+            match transport {
+                Transport::websocket => {
+                    let url = url.as_deref().unwrap_or(MARKET_EVENTS_URL);
+                    let payload = subscription.payload(&session.sessionid);
+                    Socket::connect(url, payload.to_string(), Some(idle_timeout))
+                }
+                Transport::http => {
+                    let url = url.as_deref().unwrap_or(&session.url);
+                    let form = subscription.form(&session.sessionid);
+                    let request = client.build_request_stream(url, Some(form));
+                    Socket::open(client.bearer_request(request)?, idle_timeout)
+                }
+            }
+        };
+        self.stream.next(connect, parse_events)
+    }
+}
+
+impl blocking::TradierClient {
+    /// Streams `subscription` with the client's token. Tradier only offers
+    /// streaming on the live endpoint.
+    pub fn stream_market_events(&self, subscription: Subscription) -> MarketStream {
+        MarketStream::new(self.clone(), subscription)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{net::TcpListener, sync::atomic::Ordering, thread, time::Duration};
+
+    use chrono::Utc;
+    use mockito::{mock, Matcher};
+    use tungstenite::Message;
+
+    use crate::{
+        blocking,
+        oauth::{MemoryTokenStore, OAuth, Token},
+        streaming::{
+            market_events::{Filter, MarketEvent, Subscription, Transport},
+            test_server,
+        },
+        TradierConfig,
+    };
+
+    #[test]
+    fn test_market_stream_reconnects() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let server = thread::spawn(move || {
+            let mut subscriptions = vec![];
+            for (i, stream) in listener.incoming().take(2).enumerate() {
+                let mut socket = tungstenite::accept(stream.unwrap()).unwrap();
+                let subscription = socket.read().unwrap().into_text().unwrap();
+                subscriptions
+                    .push(serde_json::from_str::<serde_json::Value>(&subscription).unwrap());
+                if i == 0 {
+                    // Two events in one frame, then the connection just drops.
+                    socket
+                        .send(Message::Text(
+                            include_str!("test_requests/market_events.txt").into(),
+                        ))
+                        .unwrap();
+                } else {
+                    socket
+                        .send(Message::Text(
+                            include_str!("test_requests/market_summary.txt").into(),
+                        ))
+                        .unwrap();
+                    // Wait for the client to hang up.
+                    while socket.read().is_ok() {}
+                }
+            }
+            subscriptions
+        });
+
+        let session = mock("POST", "/v1/markets/events/session")
+            .with_status(200)
+            .with_body(include_str!(
+                "../market_data/test_requests/create_events_session.json"
+            ))
+            .expect(2)
+            .create();
+
+        let client = blocking::TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+        let subscription = Subscription::new(vec!["SPY".into(), "AAPL".into()]).filter(vec![
+            Filter::trade,
+            Filter::quote,
+            Filter::summary,
+        ]);
+        let events: Vec<MarketEvent> = client
+            .stream_market_events(subscription)
+            .url(url)
+            .reconnect_delay(Duration::from_millis(10))
+            .take(3)
+            .map(|event| event.unwrap())
+            .collect();
+
+        match &events[0] {
+            MarketEvent::trade(trade) => {
+                assert_eq!(trade.symbol, "SPY");
+                assert_eq!(trade.price, 281.1);
+                assert_eq!(trade.cvol, 32617466);
+                assert_eq!(trade.date.timestamp_millis(), 1557758874355);
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+        match &events[1] {
+            MarketEvent::quote(quote) => {
+                assert_eq!(quote.bid, 281.84);
+                assert_eq!(quote.asksz, 6);
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+        match &events[2] {
+            MarketEvent::summary(summary) => {
+                assert_eq!(summary.symbol, "AAPL");
+                assert_eq!(summary.prev_close, Some(288.1));
+                assert_eq!(summary.close, None);
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+
+        let subscriptions = server.join().unwrap();
+        assert_eq!(subscriptions.len(), 2);
+        for subscription in subscriptions {
+            assert_eq!(
+                subscription["sessionid"],
+                "c8638963-a6d4-4fb9-9bc6-e25fbd8c60c3"
+            );
+            assert_eq!(subscription["symbols"], serde_json::json!(["SPY", "AAPL"]));
+            assert_eq!(
+                subscription["filter"],
+                serde_json::json!(["trade", "quote", "summary"])
+            );
+        }
+        session.assert();
+    }
+
+    #[test]
+    fn test_market_stream_idle_timeout() {
+        let (url, server) = test_server::websocket(vec![
+            include_str!("test_requests/market_events.txt"),
+            include_str!("test_requests/market_summary.txt"),
+        ]);
+
+        let session = mock("POST", "/v1/markets/events/session")
+            .with_status(200)
+            .with_body(include_str!(
+                "../market_data/test_requests/create_events_session.json"
+            ))
+            .expect(2)
+            .create();
+
+        let client = blocking::TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+        let events: Vec<MarketEvent> = client
+            .stream_market_events(Subscription::new(vec!["SPY".into(), "AAPL".into()]))
+            .url(url)
+            .idle_timeout(Duration::from_millis(200))
+            .reconnect_delay(Duration::from_millis(10))
+            .take(3)
+            .map(|event| event.unwrap())
+            .collect();
+
+        assert!(matches!(events[2], MarketEvent::summary(_)));
+        assert_eq!(server.join().unwrap().len(), 2);
+        session.assert();
+    }
+
+    #[test]
+    fn test_market_stream_http_outlives_idle_timeout() {
+        let (url, connections) = test_server::http(
+            include_str!("test_requests/market_summary.txt"),
+            Duration::from_millis(100),
+        );
+        let session = mock("POST", "/v1/markets/events/session")
+            .with_status(200)
+            .with_body(include_str!(
+                "../market_data/test_requests/create_events_session.json"
+            ))
+            .expect(1)
+            .create();
+
+        // Events keep arriving for well over the idle timeout.
+        let client = blocking::TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+        let events: Vec<MarketEvent> = client
+            .stream_market_events(Subscription::new(vec!["AAPL".into()]))
+            .transport(Transport::http)
+            .url(url)
+            .idle_timeout(Duration::from_millis(300))
+            .take(10)
+            .map(|event| event.unwrap())
+            .collect();
+
+        assert_eq!(events.len(), 10);
+        assert_eq!(connections.load(Ordering::SeqCst), 1);
+        session.assert();
+    }
+
+    #[test]
+    fn test_market_stream_http() {
+        let session = mock("POST", "/v1/markets/events/session")
+            .with_status(200)
+            .with_body(include_str!(
+                "../market_data/test_requests/create_events_session.json"
+            ))
+            .expect(1)
+            .create();
+        let _events = mock("POST", "/v1/markets/events")
+            .match_body(Matcher::AllOf(vec![
+                Matcher::UrlEncoded(
+                    "sessionid".into(),
+                    "c8638963-a6d4-4fb9-9bc6-e25fbd8c60c3".into(),
+                ),
+                Matcher::UrlEncoded("symbols".into(), "SPY,AAPL".into()),
+                Matcher::UrlEncoded("filter".into(), "trade,quote,summary".into()),
+            ]))
+            .with_status(200)
+            .with_body(concat!(
+                include_str!("test_requests/market_events.txt"),
+                include_str!("test_requests/market_summary.txt")
+            ))
+            .create();
+
+        let client = blocking::TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+        let subscription = Subscription::new(vec!["SPY".into(), "AAPL".into()]).filter(vec![
+            Filter::trade,
+            Filter::quote,
+            Filter::summary,
+        ]);
+        let events: Vec<MarketEvent> = client
+            .stream_market_events(subscription)
+            .transport(Transport::http)
+            .url(format!("{}/v1/markets/events", mockito::server_url()))
+            .take(3)
+            .map(|event| event.unwrap())
+            .collect();
+
+        let symbols: Vec<&str> = events.iter().map(|event| event.symbol()).collect();
+        assert_eq!(symbols, vec!["SPY", "AAPL", "AAPL"]);
+        assert!(matches!(events[2], MarketEvent::summary(_)));
+        session.assert();
+    }
+
+    #[test]
+    fn test_market_stream_http_oauth() {
+        let session = mock("POST", "/v1/markets/events/session")
+            .match_header("authorization", "Bearer oauth-token")
+            .with_status(200)
+            .with_body(include_str!(
+                "../market_data/test_requests/create_events_session.json"
+            ))
+            .create();
+        let events = mock("POST", "/v1/markets/events")
+            .match_header("authorization", "Bearer oauth-token")
+            .with_status(200)
+            .with_body(include_str!("test_requests/market_summary.txt"))
+            .create();
+
+        let token = Token {
+            access_token: "oauth-token".into(),
+            refresh_token: None,
+            scope: None,
+            expires_at: Some(Utc::now() + chrono::Duration::hours(1)),
+        };
+        let oauth = OAuth::new("app", "secret", MemoryTokenStore::new(Some(token)));
+        let client =
+            blocking::TradierClient::builder(TradierConfig::new("", mockito::server_url()))
+                .oauth(oauth)
+                .build_blocking()
+                .unwrap();
+        let event = client
+            .stream_market_events(Subscription::new(vec!["AAPL".into()]))
+            .transport(Transport::http)
+            .url(format!("{}/v1/markets/events", mockito::server_url()))
+            .next()
+            .unwrap()
+            .unwrap();
+
+        assert!(matches!(event, MarketEvent::summary(_)));
+        session.assert();
+        events.assert();
+    }
+}
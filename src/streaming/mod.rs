@@ -0,0 +1,21 @@
+//! Blocking iterators over Tradier's streaming APIs. Each stream opens its
+//! own session, subscribes, and reconnects with a fresh session when the
+//! connection drops.
+//!
+//! Streams are only available on the
+//! [`blocking::TradierClient`](crate::blocking::TradierClient), which is why
+//! the `streaming` feature turns on `blocking`. The async
+//! [`TradierClient`](crate::TradierClient) has no streaming API; from tokio,
+//! run the iterator on its own thread, e.g. with
+//! `tokio::task::spawn_blocking`, and forward events over a channel.
+
+pub mod account_events;
+
+mod de;
+
+pub mod market_events;
+
+mod socket;
+
+#[cfg(test)]
+mod test_server;
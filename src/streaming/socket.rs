@@ -0,0 +1,226 @@
+use std::{
+    collections::VecDeque,
+    io::{self, BufRead, BufReader},
+    net::TcpStream,
+    sync::mpsc::{self, Receiver, RecvTimeoutError},
+    thread,
+    time::Duration,
+};
+
+use reqwest::blocking::RequestBuilder;
+use tungstenite::{stream::MaybeTlsStream, Message, WebSocket};
+
+use crate::{error::status_error, Result, TradierError};
+
+/// A subscribed connection, either a WebSocket or a long-lived HTTP
+/// response with one event per line.
+#[derive(Debug)]
+pub(crate) enum Socket {
+    WebSocket(Box<WebSocket<MaybeTlsStream<TcpStream>>>),
+    Http {
+        lines: Receiver<Result<String>>,
+        idle_timeout: Duration,
+    },
+}
+
+impl Socket {
+    /// Connects to `url` and sends `subscription` as the first message. With
+    /// a `read_timeout`, a connection that goes quiet for longer fails the
+    /// next read instead of blocking forever.
+    pub(crate) fn connect(
+        url: &str,
+        subscription: String,
+        read_timeout: Option<Duration>,
+    ) -> Result<Self> {
+        let (mut socket, _) = tungstenite::connect(url)?;
+        match socket.get_mut() {
+            MaybeTlsStream::Plain(stream) => stream.set_read_timeout(read_timeout)?,
+            MaybeTlsStream::NativeTls(stream) => stream.get_ref().set_read_timeout(read_timeout)?,
+            _ => {}
+        }
+        socket.send(Message::Text(subscription))?;
+        Ok(Socket::WebSocket(Box::new(socket)))
+    }
+
+    /// Sends a subscription `request` whose response body is the stream. A
+    /// body that goes quiet for longer than `idle_timeout` fails the next read.
+    ///
+    /// `request` must not carry an overall timeout, as reqwest would cut the
+    /// body off once it expires however busy the stream is.
+    pub(crate) fn open(request: RequestBuilder, idle_timeout: Duration) -> Result<Self> {
+        let response = request.send()?;
+        let status = response.status();
+        if !status.is_success() {
+            let headers = response.headers().clone();
+            return Err(status_error(status, &headers, response.text()?));
+        }
+
+        // reqwest has no per-read timeout, so lines are read on their own
+        // thread, which exits once the connection closes or errors.
+        let (sender, lines) = mpsc::channel();
+        thread::spawn(move || {
+            let mut reader = BufReader::new(response);
+            loop {
+                let mut line = String::new();
+                match reader.read_line(&mut line) {
+                    Ok(0) => break,
+                    Ok(_) => {
+                        if sender.send(Ok(line)).is_err() {
+                            break;
+                        }
+                    }
+                    Err(error) => {
+                        let _ = sender.send(Err(error.into()));
+                        break;
+                    }
+                }
+            }
+        });
+        Ok(Socket::Http {
+            lines,
+            idle_timeout,
+        })
+    }
+
+    /// The next chunk of events, or `None` once the server has closed the connection.
+    pub(crate) fn read_text(&mut self) -> Result<Option<String>> {
+        match self {
+            Socket::WebSocket(socket) => loop {
+                match socket.read() {
+                    Ok(Message::Text(text)) => return Ok(Some(text)),
+                    Ok(Message::Binary(data)) => {
+                        return Ok(Some(String::from_utf8_lossy(&data).into_owned()))
+                    }
+                    Ok(Message::Close(_))
+                    | Err(tungstenite::Error::ConnectionClosed)
+                    | Err(tungstenite::Error::AlreadyClosed) => return Ok(None),
+                    // Pings are answered by tungstenite itself.
+                    Ok(_) => {}
+                    Err(error) => return Err(error.into()),
+                }
+            },
+            Socket::Http {
+                lines,
+                idle_timeout,
+            } => match lines.recv_timeout(*idle_timeout) {
+                Ok(line) => line.map(Some),
+                Err(RecvTimeoutError::Disconnected) => Ok(None),
+                Err(RecvTimeoutError::Timeout) => Err(io::Error::new(
+                    io::ErrorKind::TimedOut,
+                    "no data within the idle timeout",
+                )
+                .into()),
+            },
+        }
+    }
+}
+
+/// Counts consecutive connection attempts that haven't delivered any data.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Retry {
+    pub(crate) max_reconnects: Option<u32>,
+    pub(crate) delay: Duration,
+    attempts: u32,
+}
+
+impl Retry {
+    pub(crate) fn new(max_reconnects: Option<u32>, delay: Duration) -> Self {
+        Retry {
+            max_reconnects,
+            delay,
+            attempts: 0,
+        }
+    }
+
+    /// Waits out the delay before every attempt but the first, and returns
+    /// `false` once `max_reconnects` reconnects in a row have come to nothing.
+    pub(crate) fn attempt(&mut self) -> bool {
+        if self.attempts > 0 {
+            if self.max_reconnects.is_some_and(|max| self.attempts > max) {
+                return false;
+            }
+            thread::sleep(self.delay);
+        }
+        self.attempts += 1;
+        true
+    }
+
+    /// Data arrived, so the current connection counts as healthy.
+    pub(crate) fn succeeded(&mut self) {
+        self.attempts = 1;
+    }
+}
+
+/// The connect, read and reconnect loop shared by the stream iterators.
+#[derive(Debug)]
+pub(crate) struct Reconnecting<T> {
+    name: &'static str,
+    pub(crate) retry: Retry,
+    socket: Option<Socket>,
+    pending: VecDeque<Result<T>>,
+    last_error: Option<TradierError>,
+    finished: bool,
+}
+
+impl<T> Reconnecting<T> {
+    pub(crate) fn new(name: &'static str) -> Self {
+        Reconnecting {
+            name,
+            retry: Retry::new(Some(5), Duration::from_secs(1)),
+            socket: None,
+            pending: VecDeque::new(),
+            last_error: None,
+            finished: false,
+        }
+    }
+
+    /// The next decoded item, connecting with `connect` whenever there's no
+    /// live socket. Every data frame counts as a sign of life, even if
+    /// `parse` makes nothing of it.
+    pub(crate) fn next(
+        &mut self,
+        connect: impl Fn() -> Result<Socket>,
+        parse: impl Fn(&str) -> Vec<Result<T>>,
+    ) -> Option<Result<T>> {
+        loop {
+            if let Some(item) = self.pending.pop_front() {
+                return Some(item);
+            }
+            if self.finished {
+                return None;
+            }
+
+            let Some(socket) = self.socket.as_mut() else {
+                if !self.retry.attempt() {
+                    self.finished = true;
+                    return self.last_error.take().map(Err);
+                }
+                match connect() {
+                    Ok(socket) => self.socket = Some(socket),
+                    Err(error) => {
+                        log::warn!("{} failed to connect: {}", self.name, error);
+                        self.last_error = Some(error);
+                    }
+                }
+                continue;
+            };
+
+            match socket.read_text() {
+                Ok(Some(chunk)) => {
+                    self.retry.succeeded();
+                    self.last_error = None;
+                    self.pending.extend(parse(&chunk));
+                }
+                Ok(None) => {
+                    log::warn!("{} closed by the server, reconnecting", self.name);
+                    self.socket = None;
+                }
+                Err(error) => {
+                    log::warn!("{} dropped: {}", self.name, error);
+                    self.last_error = Some(error);
+                    self.socket = None;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,75 @@
+//! Local servers for the stream tests.
+
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpListener,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+use tungstenite::Message;
+
+/// Accepts one WebSocket connection per frame. Each reads the subscription,
+/// sends its frame and then goes quiet until the client gives up on it.
+/// Returns the URL and a handle yielding the subscriptions received.
+pub(crate) fn websocket(frames: Vec<&'static str>) -> (String, JoinHandle<Vec<serde_json::Value>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("ws://{}", listener.local_addr().unwrap());
+    let server = thread::spawn(move || {
+        let mut subscriptions = vec![];
+        for (frame, stream) in frames.into_iter().zip(listener.incoming()) {
+            let mut socket = tungstenite::accept(stream.unwrap()).unwrap();
+            let subscription = socket.read().unwrap().into_text().unwrap();
+            subscriptions.push(serde_json::from_str(&subscription).unwrap());
+            socket.send(Message::Text(frame.to_string())).unwrap();
+            while socket.read().is_ok() {}
+        }
+        subscriptions
+    });
+    (url, server)
+}
+
+/// Answers every request with a chunked response that repeats `line` every
+/// `interval` until the client hangs up. Returns the URL and a count of the
+/// connections accepted.
+pub(crate) fn http(line: &'static str, interval: Duration) -> (String, Arc<AtomicUsize>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let connections = Arc::new(AtomicUsize::new(0));
+    let accepted = connections.clone();
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            accepted.fetch_add(1, Ordering::SeqCst);
+            thread::spawn(move || {
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut content_length = 0;
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    let header = header.trim().to_lowercase();
+                    if header.is_empty() {
+                        break;
+                    }
+                    if let Some(length) = header.strip_prefix("content-length:") {
+                        content_length = length.trim().parse().unwrap();
+                    }
+                }
+                reader.read_exact(&mut vec![0; content_length]).unwrap();
+
+                stream
+                    .write_all(b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n")
+                    .unwrap();
+                let chunk = format!("{:x}\r\n{}\r\n", line.len(), line);
+                while stream.write_all(chunk.as_bytes()).is_ok() {
+                    thread::sleep(interval);
+                }
+            });
+        }
+    });
+    (url, connections)
+}
@@ -0,0 +1,314 @@
+#![allow(non_camel_case_types)]
+
+use optimistic_derives::*;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "blocking")]
+use crate::blocking;
+use crate::{
+    trading::{invalid, orders::OrderResponse},
+    Class, Duration, OrderType, Result, Side, TradierClient,
+};
+
+/// One leg of an `oto`, `oco` or `otoco` order. Each leg is a full single
+/// order with its own type, prices and duration.
+#[optimistic_no_ceho]
+pub struct AdvancedLeg {
+    pub symbol: String,
+    pub option_symbol: Option<String>,
+    pub side: Side,
+    pub quantity: u64,
+    pub order_type: OrderType,
+    pub duration: Duration,
+    pub price: Option<f64>,
+    pub stop: Option<f64>,
+}
+
+impl AdvancedLeg {
+    pub fn new(
+        symbol: impl Into<String>,
+        side: Side,
+        quantity: u64,
+        order_type: OrderType,
+        duration: Duration,
+    ) -> Self {
+        AdvancedLeg {
+            symbol: symbol.into(),
+            option_symbol: None,
+            side,
+            quantity,
+            order_type,
+            duration,
+            price: None,
+            stop: None,
+        }
+    }
+
+    pub fn option_symbol(mut self, option_symbol: impl Into<String>) -> Self {
+        self.option_symbol = Some(option_symbol.into());
+        self
+    }
+
+    pub fn price(mut self, price: f64) -> Self {
+        self.price = Some(price);
+        self
+    }
+
+    pub fn stop(mut self, stop: f64) -> Self {
+        self.stop = Some(stop);
+        self
+    }
+}
+
+/// `oto` and `oco` take two legs and `otoco` three. The legs of an `oco`, and
+/// the last two legs of an `otoco`, form an OCO pair that must trade the same
+/// security; an `oto` can trigger an order for anything.
+fn validate(class: Class, legs: &[AdvancedLeg]) -> Result<()> {
+    let expected = match class {
+        Class::oto | Class::oco => 2,
+        Class::otoco => 3,
+        other => return invalid(format!("{} is not an advanced order class", other)),
+    };
+    if legs.len() != expected {
+        return invalid(format!(
+            "{} orders take {} legs, got {}",
+            class,
+            expected,
+            legs.len()
+        ));
+    }
+
+    for (i, leg) in legs.iter().enumerate() {
+        if leg.quantity == 0 {
+            return invalid(format!("leg {} quantity must be greater than 0", i));
+        }
+        let (needs_price, needs_stop) = match leg.order_type {
+            OrderType::market => (false, false),
+            OrderType::limit => (true, false),
+            OrderType::stop => (false, true),
+            OrderType::stop_limit => (true, true),
+            other => return invalid(format!("leg {} can't be a {} order", i, other)),
+        };
+        if needs_price && leg.price.is_none() {
+            return invalid(format!(
+                "leg {} is a {} order without a price",
+                i, leg.order_type
+            ));
+        }
+        if needs_stop && leg.stop.is_none() {
+            return invalid(format!(
+                "leg {} is a {} order without a stop",
+                i, leg.order_type
+            ));
+        }
+    }
+
+    if class == Class::oto {
+        return Ok(());
+    }
+    let pair = &legs[expected - 2..];
+    if (&pair[0].symbol, &pair[0].option_symbol) != (&pair[1].symbol, &pair[1].option_symbol) {
+        return invalid("both sides of an OCO pair must be the same security".into());
+    }
+    Ok(())
+}
+
+/// Form fields with each leg's `symbol[i]`, `side[i]`, `type[i]` and so on.
+/// The top level `duration` Tradier documents is taken from the first leg.
+fn form(class: Class, legs: &[AdvancedLeg], tag: Option<String>) -> Vec<(String, String)> {
+    let mut form = vec![
+        ("class".to_string(), class.to_string()),
+        ("duration".to_string(), legs[0].duration.to_string()),
+    ];
+    for (i, leg) in legs.iter().enumerate() {
+        form.push((format!("symbol[{}]", i), leg.symbol.clone()));
+        if let Some(option_symbol) = &leg.option_symbol {
+            form.push((format!("option_symbol[{}]", i), option_symbol.clone()));
+        }
+        form.push((format!("side[{}]", i), leg.side.to_string()));
+        form.push((format!("quantity[{}]", i), leg.quantity.to_string()));
+        form.push((format!("type[{}]", i), leg.order_type.to_string()));
+        form.push((format!("duration[{}]", i), leg.duration.to_string()));
+        if let Some(price) = leg.price {
+            form.push((format!("price[{}]", i), price.to_string()));
+        }
+        if let Some(stop) = leg.stop {
+            form.push((format!("stop[{}]", i), stop.to_string()));
+        }
+    }
+    if let Some(tag) = tag {
+        form.push(("tag".into(), tag));
+    }
+    form
+}
+
+#[cfg(feature = "blocking")]
+impl blocking::TradierClient {
+    /// Places an `oto`, `oco` or `otoco` order. The legs are checked before
+    /// anything is sent.
+    pub fn post_advanced_order(
+        &self,
+        account_id: String,
+        class: Class,
+        legs: Vec<AdvancedLeg>,
+        tag: Option<String>,
+    ) -> Result<OrderResponse> {
+        validate(class, &legs)?;
+        for leg in legs.iter().filter(|leg| leg.option_symbol.is_none()) {
+            self.check_short_sale(&leg.symbol, leg.side)?;
+        }
+        let body = form(class, &legs, tag);
+
+        let request = self.build_request_post(
+            &format!("accounts/{}/orders", account_id),
+            Some(body),
+            None::<()>,
+        );
+        let order_response: Result<OrderResponse> = self.send(request);
+        log::debug!("order_response: {:?}", order_response);
+        order_response
+    }
+}
+
+impl TradierClient {
+    /// Places an `oto`, `oco` or `otoco` order. The legs are checked before
+    /// anything is sent.
+    pub async fn post_advanced_order(
+        &self,
+        account_id: String,
+        class: Class,
+        legs: Vec<AdvancedLeg>,
+        tag: Option<String>,
+    ) -> Result<OrderResponse> {
+        validate(class, &legs)?;
+        for leg in legs.iter().filter(|leg| leg.option_symbol.is_none()) {
+            self.check_short_sale(&leg.symbol, leg.side).await?;
+        }
+        let body = form(class, &legs, tag);
+
+        let request = self.build_request_post(
+            &format!("accounts/{}/orders", account_id),
+            Some(body),
+            None::<()>,
+        );
+        let order_response: Result<OrderResponse> = self.send(request).await;
+        log::debug!("order_response: {:?}", order_response);
+        order_response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::{mock, Matcher};
+
+    use crate::{
+        trading::advanced::AdvancedLeg, Class, Duration, OrderType, Side, TradierClient,
+        TradierConfig, TradierError,
+    };
+
+    fn bracket() -> Vec<AdvancedLeg> {
+        vec![
+            AdvancedLeg::new("SPY", Side::buy, 10, OrderType::limit, Duration::day).price(280.0),
+            AdvancedLeg::new("SPY", Side::sell, 10, OrderType::limit, Duration::gtc).price(290.0),
+            AdvancedLeg::new("SPY", Side::sell, 10, OrderType::stop, Duration::gtc).stop(275.0),
+        ]
+    }
+
+    #[tokio::test]
+    async fn test_post_advanced_order() {
+        let _m = mock("POST", "/v1/accounts/VA000000/orders")
+            .match_body(Matcher::AllOf(vec![
+                Matcher::Regex("^class=otoco&duration=day&".into()),
+                Matcher::Regex(
+                    "symbol%5B0%5D=SPY&side%5B0%5D=buy&quantity%5B0%5D=10&type%5B0%5D=limit&duration%5B0%5D=day&price%5B0%5D=280"
+                        .into(),
+                ),
+                Matcher::Regex("type%5B2%5D=stop&duration%5B2%5D=gtc&stop%5B2%5D=275$".into()),
+            ]))
+            .with_status(200)
+            .with_body(include_str!("test_requests/post_order.json"))
+            .create();
+
+        let client = TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        let response = client
+            .post_advanced_order("VA000000".into(), Class::otoco, bracket(), None)
+            .await;
+        assert_eq!(response.unwrap().order.id, 257459);
+    }
+
+    #[tokio::test]
+    async fn test_post_oto_across_securities() {
+        let _m = mock("POST", "/v1/accounts/VA000000/orders")
+            .match_body(Matcher::AllOf(vec![
+                Matcher::Regex("^class=oto&".into()),
+                Matcher::Regex("symbol%5B0%5D=AAPL&side%5B0%5D=buy".into()),
+                Matcher::Regex(
+                    "symbol%5B1%5D=AAPL&option_symbol%5B1%5D=AAPL190621C00195000&side%5B1%5D=sell_to_open"
+                        .into(),
+                ),
+            ]))
+            .with_status(200)
+            .with_body(include_str!("test_requests/post_order.json"))
+            .create();
+
+        let client = TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        let legs = vec![
+            AdvancedLeg::new("AAPL", Side::buy, 100, OrderType::limit, Duration::day).price(190.0),
+            AdvancedLeg::new(
+                "AAPL",
+                Side::sell_to_open,
+                1,
+                OrderType::limit,
+                Duration::day,
+            )
+            .option_symbol("AAPL190621C00195000")
+            .price(2.5),
+        ];
+        let response = client
+            .post_advanced_order("VA000000".into(), Class::oto, legs, None)
+            .await;
+        assert_eq!(response.unwrap().order.id, 257459);
+    }
+
+    #[tokio::test]
+    async fn test_post_advanced_order_invalid() {
+        let client = TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        assert!(matches!(
+            client
+                .post_advanced_order("VA000000".into(), Class::oco, bracket(), None)
+                .await,
+            Err(TradierError::InvalidOrder(_))
+        ));
+
+        let mut no_stop = bracket();
+        no_stop[2].stop = None;
+        assert!(matches!(
+            client
+                .post_advanced_order("VA000000".into(), Class::otoco, no_stop, None)
+                .await,
+            Err(TradierError::InvalidOrder(_))
+        ));
+
+        let mut unpaired = bracket();
+        unpaired.remove(0);
+        unpaired[1].symbol = "QQQ".into();
+        assert!(matches!(
+            client
+                .post_advanced_order("VA000000".into(), Class::oco, unpaired, None)
+                .await,
+            Err(TradierError::InvalidOrder(_))
+        ));
+
+        let mut mismatched = bracket();
+        mismatched[2].symbol = "QQQ".into();
+        assert!(matches!(
+            client
+                .post_advanced_order("VA000000".into(), Class::otoco, mismatched, None)
+                .await,
+            Err(TradierError::InvalidOrder(_))
+        ));
+    }
+}
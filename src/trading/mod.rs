@@ -1 +1,12 @@
+use crate::{Result, TradierError};
+
+pub mod advanced;
+
+pub mod multileg;
+
 pub mod orders;
+
+/// Rejects an order before it's sent.
+pub(crate) fn invalid<T>(message: String) -> Result<T> {
+    Err(TradierError::InvalidOrder(message))
+}
@@ -0,0 +1,302 @@
+#![allow(non_camel_case_types)]
+
+use std::collections::HashSet;
+
+use optimistic_derives::*;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "blocking")]
+use crate::blocking;
+use crate::{
+    trading::{invalid, orders::OrderResponse},
+    Class, Duration, OrderType, Result, Side, TradierClient,
+};
+
+const OPTION_SIDES: [Side; 4] = [
+    Side::buy_to_open,
+    Side::buy_to_close,
+    Side::sell_to_open,
+    Side::sell_to_close,
+];
+
+const EQUITY_SIDES: [Side; 4] = [Side::buy, Side::buy_to_cover, Side::sell, Side::sell_short];
+
+/// One leg of a `multileg` or `combo` order. Legs without an `option_symbol`
+/// are equity legs, which only `combo` orders accept.
+#[optimistic_no_c]
+pub struct Leg {
+    pub option_symbol: Option<String>,
+    pub side: Side,
+    pub quantity: u64,
+}
+
+impl Leg {
+    pub fn option(option_symbol: impl Into<String>, side: Side, quantity: u64) -> Self {
+        Leg {
+            option_symbol: Some(option_symbol.into()),
+            side,
+            quantity,
+        }
+    }
+
+    pub fn equity(side: Side, quantity: u64) -> Self {
+        Leg {
+            option_symbol: None,
+            side,
+            quantity,
+        }
+    }
+}
+
+/// Checks the leg layout Tradier accepts: 2-4 option legs for `multileg`, one
+/// equity leg plus 1-2 option legs for `combo`, with sides matching the leg kind.
+fn validate(class: Class, order_type: OrderType, price: Option<f64>, legs: &[Leg]) -> Result<()> {
+    let (equity_legs, option_legs): (Vec<&Leg>, Vec<&Leg>) =
+        legs.iter().partition(|leg| leg.option_symbol.is_none());
+
+    match class {
+        Class::multileg => {
+            if !equity_legs.is_empty() {
+                return invalid("multileg orders only take option legs".into());
+            }
+            if !(2..=4).contains(&option_legs.len()) {
+                return invalid(format!(
+                    "multileg orders take 2 to 4 legs, got {}",
+                    option_legs.len()
+                ));
+            }
+        }
+        Class::combo => {
+            if equity_legs.len() != 1 || !(1..=2).contains(&option_legs.len()) {
+                return invalid(format!(
+                    "combo orders take 1 equity leg and 1 or 2 option legs, got {} and {}",
+                    equity_legs.len(),
+                    option_legs.len()
+                ));
+            }
+        }
+        other => return invalid(format!("{} is not a multileg order class", other)),
+    }
+
+    let mut seen = HashSet::new();
+    for leg in legs {
+        if leg.quantity == 0 {
+            return invalid("leg quantity must be greater than 0".into());
+        }
+        match &leg.option_symbol {
+            Some(symbol) => {
+                if !OPTION_SIDES.contains(&leg.side) {
+                    return invalid(format!("option leg {} can't use side {}", symbol, leg.side));
+                }
+                if !seen.insert(symbol) {
+                    return invalid(format!("{} appears in more than one leg", symbol));
+                }
+            }
+            None => {
+                if !EQUITY_SIDES.contains(&leg.side) {
+                    return invalid(format!("equity leg can't use side {}", leg.side));
+                }
+            }
+        }
+    }
+
+    match order_type {
+        OrderType::market | OrderType::even => Ok(()),
+        OrderType::debit | OrderType::credit => match price {
+            Some(price) if price > 0.0 => Ok(()),
+            _ => invalid(format!("{} orders need a positive price", order_type)),
+        },
+        other => invalid(format!("{} orders can't have multiple legs", other)),
+    }
+}
+
+/// Form fields for the order, with legs as Tradier's indexed `option_symbol[i]`,
+/// `side[i]` and `quantity[i]`. A combo's equity leg goes first.
+fn form(
+    class: Class,
+    symbol: String,
+    order_type: OrderType,
+    duration: Duration,
+    price: Option<f64>,
+    legs: &[Leg],
+    tag: Option<String>,
+) -> Vec<(String, String)> {
+    let mut form = vec![
+        ("class".to_string(), class.to_string()),
+        ("symbol".to_string(), symbol),
+        ("type".to_string(), order_type.to_string()),
+        ("duration".to_string(), duration.to_string()),
+    ];
+    if let Some(price) = price {
+        form.push(("price".into(), price.to_string()));
+    }
+
+    let equity_legs = legs.iter().filter(|leg| leg.option_symbol.is_none());
+    let option_legs = legs.iter().filter(|leg| leg.option_symbol.is_some());
+    for (i, leg) in equity_legs.chain(option_legs).enumerate() {
+        if let Some(option_symbol) = &leg.option_symbol {
+            form.push((format!("option_symbol[{}]", i), option_symbol.clone()));
+        }
+        form.push((format!("side[{}]", i), leg.side.to_string()));
+        form.push((format!("quantity[{}]", i), leg.quantity.to_string()));
+    }
+
+    if let Some(tag) = tag {
+        form.push(("tag".into(), tag));
+    }
+    form
+}
+
+#[cfg(feature = "blocking")]
+impl blocking::TradierClient {
+    /// Places a `multileg` or `combo` order. `order_type` must be `market`,
+    /// `debit`, `credit` or `even`; the legs are checked before anything is sent.
+    #[allow(clippy::too_many_arguments)]
+    pub fn post_multileg_order(
+        &self,
+        account_id: String,
+        class: Class,
+        symbol: String,
+        order_type: OrderType,
+        duration: Duration,
+        price: Option<f64>,
+        legs: Vec<Leg>,
+        tag: Option<String>,
+    ) -> Result<OrderResponse> {
+        validate(class, order_type, price, &legs)?;
+        for leg in legs.iter().filter(|leg| leg.option_symbol.is_none()) {
+            self.check_short_sale(&symbol, leg.side)?;
+        }
+        let body = form(class, symbol, order_type, duration, price, &legs, tag);
+
+        let request = self.build_request_post(
+            &format!("accounts/{}/orders", account_id),
+            Some(body),
+            None::<()>,
+        );
+        let order_response: Result<OrderResponse> = self.send(request);
+        log::debug!("order_response: {:?}", order_response);
+        order_response
+    }
+}
+
+impl TradierClient {
+    /// Places a `multileg` or `combo` order. `order_type` must be `market`,
+    /// `debit`, `credit` or `even`; the legs are checked before anything is sent.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn post_multileg_order(
+        &self,
+        account_id: String,
+        class: Class,
+        symbol: String,
+        order_type: OrderType,
+        duration: Duration,
+        price: Option<f64>,
+        legs: Vec<Leg>,
+        tag: Option<String>,
+    ) -> Result<OrderResponse> {
+        validate(class, order_type, price, &legs)?;
+        for leg in legs.iter().filter(|leg| leg.option_symbol.is_none()) {
+            self.check_short_sale(&symbol, leg.side).await?;
+        }
+        let body = form(class, symbol, order_type, duration, price, &legs, tag);
+
+        let request = self.build_request_post(
+            &format!("accounts/{}/orders", account_id),
+            Some(body),
+            None::<()>,
+        );
+        let order_response: Result<OrderResponse> = self.send(request).await;
+        log::debug!("order_response: {:?}", order_response);
+        order_response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::{mock, Matcher};
+
+    use crate::{
+        trading::multileg::Leg, Class, Duration, OrderType, Side, TradierClient, TradierConfig,
+        TradierError,
+    };
+
+    #[tokio::test]
+    async fn test_post_multileg_order() {
+        let _m = mock("POST", "/v1/accounts/VA000000/orders")
+            .match_body(Matcher::AllOf(vec![
+                Matcher::Regex("class=multileg&symbol=SPY&type=debit&duration=day&price=1.25".into()),
+                Matcher::Regex(
+                    "option_symbol%5B0%5D=SPY190621C00290000&side%5B0%5D=buy_to_open&quantity%5B0%5D=1"
+                        .into(),
+                ),
+                Matcher::Regex(
+                    "option_symbol%5B1%5D=SPY190621C00295000&side%5B1%5D=sell_to_open&quantity%5B1%5D=1"
+                        .into(),
+                ),
+            ]))
+            .with_status(200)
+            .with_body(include_str!("test_requests/post_order.json"))
+            .create();
+
+        let client = TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        let response = client
+            .post_multileg_order(
+                "VA000000".into(),
+                Class::multileg,
+                "SPY".into(),
+                OrderType::debit,
+                Duration::day,
+                Some(1.25),
+                vec![
+                    Leg::option("SPY190621C00290000", Side::buy_to_open, 1),
+                    Leg::option("SPY190621C00295000", Side::sell_to_open, 1),
+                ],
+                None,
+            )
+            .await;
+        assert_eq!(response.unwrap().order.id, 257459);
+    }
+
+    #[tokio::test]
+    async fn test_post_multileg_order_invalid() {
+        let client = TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+        let post = |class, legs| {
+            client.post_multileg_order(
+                "VA000000".into(),
+                class,
+                "SPY".into(),
+                OrderType::even,
+                Duration::day,
+                None,
+                legs,
+                None,
+            )
+        };
+
+        let single = vec![Leg::option("SPY190621C00290000", Side::buy_to_open, 1)];
+        assert!(matches!(
+            post(Class::multileg, single).await,
+            Err(TradierError::InvalidOrder(_))
+        ));
+
+        let equity_side = vec![
+            Leg::option("SPY190621C00290000", Side::buy, 1),
+            Leg::option("SPY190621C00295000", Side::sell_to_open, 1),
+        ];
+        assert!(matches!(
+            post(Class::multileg, equity_side).await,
+            Err(TradierError::InvalidOrder(_))
+        ));
+
+        let no_equity = vec![
+            Leg::option("SPY190621C00290000", Side::sell_to_open, 1),
+            Leg::option("SPY190621P00280000", Side::buy_to_open, 1),
+        ];
+        assert!(matches!(
+            post(Class::combo, no_equity).await,
+            Err(TradierError::InvalidOrder(_))
+        ));
+    }
+}
@@ -1,13 +1,12 @@
 #![allow(non_camel_case_types)]
 
-use eyre::{eyre, Result};
+use chrono::NaiveDateTime;
 use optimistic_derives::*;
-use reqwest::StatusCode;
 use serde::{Deserialize, Serialize};
 
-use crate::{
-    build_request_del, build_request_post, Class, Duration, OrderType, Side, TradierConfig,
-};
+#[cfg(feature = "blocking")]
+use crate::blocking;
+use crate::{Class, Duration, OrderType, Result, Side, TradierClient};
 
 #[optimistic_no_c]
 pub struct Order {
@@ -33,45 +32,50 @@ struct Body {
     price: Option<f64>,
     stop: Option<f64>,
     tag: Option<String>,
+    preview: Option<bool>,
 }
 
-#[allow(clippy::too_many_arguments)]
-pub fn post_order(
-    config: &TradierConfig,
-    account_id: String,
-    class: Class,
-    symbol: String,
-    side: Side,
-    quantity: u64,
-    order_type: OrderType,
-    duration: Duration,
+/// What Tradier would do with an order, from placing it with `preview=true`.
+/// Orders that fail validation come back as [`TradierError::Validation`](crate::TradierError::Validation).
+#[optimistic_no_ceho]
+pub struct OrderPreview {
+    pub status: Option<String>,
+    pub commission: f64,
+    pub cost: f64,
+    pub fees: f64,
+    pub symbol: String,
+    pub quantity: f64,
+    pub side: Side,
+    #[serde(alias = "type")]
+    pub order_type: OrderType,
+    pub duration: Duration,
+    pub price: Option<f64>,
+    /// Whether the order passed Tradier's validation.
+    pub result: bool,
+    pub order_cost: f64,
+    /// Change in margin requirement, i.e. the buying power the order would use.
+    pub margin_change: f64,
+    pub option_requirement: Option<f64>,
+    pub request_date: NaiveDateTime,
+    pub extended_hours: bool,
+    pub class: Class,
+    pub strategy: String,
+    pub day_trades: Option<i64>,
+    pub option_symbol: Option<String>,
+}
+
+#[optimistic_no_ceho]
+pub struct OrderPreviewResponse {
+    pub order: OrderPreview,
+}
+
+#[optimistic_no_ceho]
+struct ModifyBody {
+    #[serde(rename(serialize = "type"))]
+    order_type: Option<OrderType>,
+    duration: Option<Duration>,
     price: Option<f64>,
     stop: Option<f64>,
-    tag: Option<String>,
-) -> Result<OrderResponse> {
-    let body = Body {
-        class,
-        symbol,
-        side,
-        quantity,
-        order_type,
-        duration,
-        price,
-        stop,
-        tag,
-    };
-
-    let request = build_request_post(
-        config,
-        &format!("accounts/{}/orders", account_id),
-        Some(body),
-        None::<()>,
-    );
-    let response = request.send();
-    log::debug!("response: {:?}", response);
-    let order_response: Result<OrderResponse, reqwest::Error> = response?.json();
-    log::debug!("order_response: {:?}", order_response);
-    Ok(order_response?)
 }
 
 #[optimistic_no_c]
@@ -79,33 +83,240 @@ pub struct CancelledResponse {
     order: Order,
 }
 
-pub fn cancel_order(
-    config: &TradierConfig,
-    account_id: String,
-    order_id: i64,
-) -> Result<CancelledResponse> {
-    let request = build_request_del(
-        config,
-        &format!("accounts/{}/orders/{}", account_id, order_id),
-    );
-    let response = request.send()?;
-    if response.status().clone() == StatusCode::OK {
-        let cancel: CancelledResponse = response.json()?;
+#[cfg(feature = "blocking")]
+impl blocking::TradierClient {
+    #[allow(clippy::too_many_arguments)]
+    pub fn post_order(
+        &self,
+        account_id: String,
+        class: Class,
+        symbol: String,
+        side: Side,
+        quantity: u64,
+        order_type: OrderType,
+        duration: Duration,
+        price: Option<f64>,
+        stop: Option<f64>,
+        tag: Option<String>,
+    ) -> Result<OrderResponse> {
+        self.check_short_sale(&symbol, side)?;
+        let body = Body {
+            class,
+            symbol,
+            side,
+            quantity,
+            order_type,
+            duration,
+            price,
+            stop,
+            tag,
+            preview: None,
+        };
+
+        let request = self.build_request_post(
+            &format!("accounts/{}/orders", account_id),
+            Some(body),
+            None::<()>,
+        );
+        let order_response: Result<OrderResponse> = self.send(request);
+        log::debug!("order_response: {:?}", order_response);
+        order_response
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn preview_order(
+        &self,
+        account_id: String,
+        class: Class,
+        symbol: String,
+        side: Side,
+        quantity: u64,
+        order_type: OrderType,
+        duration: Duration,
+        price: Option<f64>,
+        stop: Option<f64>,
+        tag: Option<String>,
+    ) -> Result<OrderPreviewResponse> {
+        let body = Body {
+            class,
+            symbol,
+            side,
+            quantity,
+            order_type,
+            duration,
+            price,
+            stop,
+            tag,
+            preview: Some(true),
+        };
+
+        let request = self.build_request_post(
+            &format!("accounts/{}/orders", account_id),
+            Some(body),
+            None::<()>,
+        );
+        let preview_response: Result<OrderPreviewResponse> = self.send(request);
+        log::debug!("preview_response: {:?}", preview_response);
+        preview_response
+    }
+
+    pub fn modify_order(
+        &self,
+        account_id: String,
+        order_id: i64,
+        order_type: Option<OrderType>,
+        duration: Option<Duration>,
+        price: Option<f64>,
+        stop: Option<f64>,
+    ) -> Result<OrderResponse> {
+        let body = ModifyBody {
+            order_type,
+            duration,
+            price,
+            stop,
+        };
+
+        let request = self.build_request_put(
+            &format!("accounts/{}/orders/{}", account_id, order_id),
+            Some(body),
+            None::<()>,
+        );
+        let order_response: Result<OrderResponse> = self.send(request);
+        log::debug!("order_response: {:?}", order_response);
+        order_response
+    }
+
+    pub fn cancel_order(&self, account_id: String, order_id: i64) -> Result<CancelledResponse> {
+        let request =
+            self.build_request_del(&format!("accounts/{}/orders/{}", account_id, order_id));
+        let cancel: CancelledResponse = self.send(request)?;
+        Ok(cancel)
+    }
+}
+
+impl TradierClient {
+    #[allow(clippy::too_many_arguments)]
+    pub async fn post_order(
+        &self,
+        account_id: String,
+        class: Class,
+        symbol: String,
+        side: Side,
+        quantity: u64,
+        order_type: OrderType,
+        duration: Duration,
+        price: Option<f64>,
+        stop: Option<f64>,
+        tag: Option<String>,
+    ) -> Result<OrderResponse> {
+        self.check_short_sale(&symbol, side).await?;
+        let body = Body {
+            class,
+            symbol,
+            side,
+            quantity,
+            order_type,
+            duration,
+            price,
+            stop,
+            tag,
+            preview: None,
+        };
+
+        let request = self.build_request_post(
+            &format!("accounts/{}/orders", account_id),
+            Some(body),
+            None::<()>,
+        );
+        let order_response: Result<OrderResponse> = self.send(request).await;
+        log::debug!("order_response: {:?}", order_response);
+        order_response
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn preview_order(
+        &self,
+        account_id: String,
+        class: Class,
+        symbol: String,
+        side: Side,
+        quantity: u64,
+        order_type: OrderType,
+        duration: Duration,
+        price: Option<f64>,
+        stop: Option<f64>,
+        tag: Option<String>,
+    ) -> Result<OrderPreviewResponse> {
+        let body = Body {
+            class,
+            symbol,
+            side,
+            quantity,
+            order_type,
+            duration,
+            price,
+            stop,
+            tag,
+            preview: Some(true),
+        };
+
+        let request = self.build_request_post(
+            &format!("accounts/{}/orders", account_id),
+            Some(body),
+            None::<()>,
+        );
+        let preview_response: Result<OrderPreviewResponse> = self.send(request).await;
+        log::debug!("preview_response: {:?}", preview_response);
+        preview_response
+    }
+
+    pub async fn modify_order(
+        &self,
+        account_id: String,
+        order_id: i64,
+        order_type: Option<OrderType>,
+        duration: Option<Duration>,
+        price: Option<f64>,
+        stop: Option<f64>,
+    ) -> Result<OrderResponse> {
+        let body = ModifyBody {
+            order_type,
+            duration,
+            price,
+            stop,
+        };
+
+        let request = self.build_request_put(
+            &format!("accounts/{}/orders/{}", account_id, order_id),
+            Some(body),
+            None::<()>,
+        );
+        let order_response: Result<OrderResponse> = self.send(request).await;
+        log::debug!("order_response: {:?}", order_response);
+        order_response
+    }
+
+    pub async fn cancel_order(
+        &self,
+        account_id: String,
+        order_id: i64,
+    ) -> Result<CancelledResponse> {
+        let request =
+            self.build_request_del(&format!("accounts/{}/orders/{}", account_id, order_id));
+        let cancel: CancelledResponse = self.send(request).await?;
         Ok(cancel)
-    } else {
-        Err(eyre!("{:?}", response.text()))
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use mockito::mock;
+    use mockito::{mock, Matcher};
 
-    use crate::{
-        trading::orders::{cancel_order, post_order},
-        Class, Duration, OrderType, Side, TradierConfig,
-    };
+    #[cfg(feature = "blocking")]
+    use crate::blocking;
+    use crate::{Class, Duration, OrderType, Side, TradierClient, TradierConfig, TradierError};
 
+    #[cfg(feature = "blocking")]
     #[test]
     fn test_post_order() {
         let _m = mock("POST", "/v1/accounts/VA000000/orders")
@@ -113,13 +324,9 @@ mod tests {
             .with_body(include_str!("test_requests/post_order.json"))
             .create();
 
-        let config = TradierConfig {
-            token: "xxx".into(),
-            endpoint: mockito::server_url(),
-        };
+        let client = blocking::TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
 
-        let response = post_order(
-            &config,
+        let response = client.post_order(
             "VA000000".into(),
             Class::equity,
             "AAPL".into(),
@@ -134,6 +341,7 @@ mod tests {
         assert!(response.is_ok());
     }
 
+    #[cfg(feature = "blocking")]
     #[test]
     fn test_del_order() {
         let _m = mock("DELETE", "/v1/accounts/VA000000/orders/1")
@@ -141,14 +349,129 @@ mod tests {
             .with_body(include_str!("test_requests/del_order.json"))
             .create();
 
-        let config = TradierConfig {
-            token: "xxx".into(),
-            endpoint: mockito::server_url(),
-        };
+        let client = blocking::TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
 
-        let response = cancel_order(&config, "VA000000".into(), 1);
+        let response = client.cancel_order("VA000000".into(), 1);
         println!("{:?}", response);
 
         assert!(response.is_ok());
     }
+
+    #[tokio::test]
+    async fn test_post_order_async() {
+        let _m = mock("POST", "/v1/accounts/VA000000/orders")
+            .with_status(200)
+            .with_body(include_str!("test_requests/post_order.json"))
+            .create();
+
+        let client = TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        let response = client
+            .post_order(
+                "VA000000".into(),
+                Class::equity,
+                "AAPL".into(),
+                Side::buy,
+                100,
+                OrderType::market,
+                Duration::gtc,
+                None,
+                None,
+                None,
+            )
+            .await;
+        assert!(response.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_del_order_async() {
+        let _m = mock("DELETE", "/v1/accounts/VA000000/orders/1")
+            .with_status(200)
+            .with_body(include_str!("test_requests/del_order.json"))
+            .create();
+
+        let client = TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        let response = client.cancel_order("VA000000".into(), 1).await;
+        assert!(response.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_del_order_errors() {
+        let client = TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        let _m = mock("DELETE", "/v1/accounts/VA000000/orders/2")
+            .with_status(400)
+            .with_body(include_str!("test_requests/order_error.json"))
+            .create();
+        match client.cancel_order("VA000000".into(), 2).await {
+            Err(TradierError::Validation { messages, .. }) => assert_eq!(
+                messages,
+                vec!["Backend rejected : Order cannot be canceled in current status"]
+            ),
+            other => panic!("unexpected {:?}", other),
+        }
+
+        let _m = mock("DELETE", "/v1/accounts/VA000000/orders/3")
+            .with_status(401)
+            .with_body("Invalid Access Token")
+            .create();
+        assert!(matches!(
+            client.cancel_order("VA000000".into(), 3).await,
+            Err(TradierError::Unauthorized { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_modify_order() {
+        let _m = mock("PUT", "/v1/accounts/VA000000/orders/257459")
+            .match_body("type=limit&price=187.5")
+            .with_status(200)
+            .with_body(include_str!("test_requests/put_order.json"))
+            .create();
+
+        let client = TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        let response = client
+            .modify_order(
+                "VA000000".into(),
+                257459,
+                Some(OrderType::limit),
+                None,
+                Some(187.5),
+                None,
+            )
+            .await;
+        assert_eq!(response.unwrap().order.id, 257459);
+    }
+
+    #[tokio::test]
+    async fn test_preview_order() {
+        let _m = mock("POST", "/v1/accounts/VA000000/orders")
+            .match_body(Matcher::Regex("preview=true".into()))
+            .with_status(200)
+            .with_body(include_str!("test_requests/post_order_preview.json"))
+            .create();
+
+        let client = TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        let response = client
+            .preview_order(
+                "VA000000".into(),
+                Class::equity,
+                "SPY".into(),
+                Side::buy,
+                10,
+                OrderType::limit,
+                Duration::day,
+                Some(280.5),
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        assert!(response.order.result);
+        assert_eq!(response.order.order_cost, 2805.0);
+        assert_eq!(response.order.margin_change, 1402.5);
+    }
 }
@@ -0,0 +1,453 @@
+use optimistic_derives::*;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "blocking")]
+use crate::blocking;
+use crate::{market_data::get_quotes::GetQuotes, Result, TradierClient};
+
+#[optimistic_no_c]
+pub struct WatchlistSummary {
+    pub name: String,
+    pub id: String,
+    pub public_id: Option<String>,
+}
+
+#[optimistic_no_c]
+#[serde(untagged)]
+enum WatchlistSummaryEnum {
+    Unit(WatchlistSummary),
+    Vec(Vec<WatchlistSummary>),
+}
+
+#[optimistic_no_c]
+struct NaiveWatchlists {
+    watchlist: Option<WatchlistSummaryEnum>,
+}
+
+#[optimistic_no_c]
+struct NaiveWatchlistsRoot {
+    watchlists: Option<NaiveWatchlists>,
+}
+
+#[optimistic_no_c]
+pub struct Watchlists {
+    pub watchlist: Vec<WatchlistSummary>,
+}
+
+#[optimistic_no_c]
+pub struct WatchlistsRoot {
+    pub watchlists: Watchlists,
+}
+
+impl From<NaiveWatchlistsRoot> for WatchlistsRoot {
+    fn from(item: NaiveWatchlistsRoot) -> Self {
+        let watchlist = match item.watchlists.and_then(|w| w.watchlist) {
+            Some(WatchlistSummaryEnum::Unit(unit)) => vec![unit],
+            Some(WatchlistSummaryEnum::Vec(watchlists)) => watchlists,
+            None => vec![],
+        };
+        WatchlistsRoot {
+            watchlists: Watchlists { watchlist },
+        }
+    }
+}
+
+#[optimistic_no_c]
+pub struct Item {
+    pub symbol: String,
+    pub id: String,
+}
+
+#[optimistic_no_c]
+#[serde(untagged)]
+enum ItemEnum {
+    Unit(Item),
+    Vec(Vec<Item>),
+}
+
+#[optimistic_no_c]
+struct NaiveItems {
+    item: Option<ItemEnum>,
+}
+
+#[optimistic_no_c]
+struct NaiveWatchlist {
+    name: String,
+    id: String,
+    public_id: Option<String>,
+    items: Option<NaiveItems>,
+}
+
+#[optimistic_no_c]
+struct NaiveWatchlistRoot {
+    watchlist: NaiveWatchlist,
+}
+
+#[optimistic_no_c]
+pub struct Watchlist {
+    pub name: String,
+    pub id: String,
+    pub public_id: Option<String>,
+    pub items: Vec<Item>,
+}
+
+impl Watchlist {
+    pub fn symbols(&self) -> Vec<String> {
+        self.items.iter().map(|item| item.symbol.clone()).collect()
+    }
+}
+
+#[optimistic_no_c]
+pub struct WatchlistRoot {
+    pub watchlist: Watchlist,
+}
+
+impl From<NaiveWatchlistRoot> for WatchlistRoot {
+    fn from(item: NaiveWatchlistRoot) -> Self {
+        let watchlist = item.watchlist;
+        let items = match watchlist.items.and_then(|items| items.item) {
+            Some(ItemEnum::Unit(unit)) => vec![unit],
+            Some(ItemEnum::Vec(items)) => items,
+            None => vec![],
+        };
+        WatchlistRoot {
+            watchlist: Watchlist {
+                name: watchlist.name,
+                id: watchlist.id,
+                public_id: watchlist.public_id,
+                items,
+            },
+        }
+    }
+}
+
+#[optimistic_no_c]
+struct Body {
+    name: Option<String>,
+    symbols: Option<String>,
+}
+
+impl Body {
+    fn new(name: Option<String>, symbols: Option<Vec<String>>) -> Self {
+        Body {
+            name,
+            symbols: symbols.map(|symbols| symbols.join(",")),
+        }
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl blocking::TradierClient {
+    pub fn get_watchlists(&self) -> Result<WatchlistsRoot> {
+        let request = self.build_request_get("watchlists", None::<()>, None::<()>);
+        let response: NaiveWatchlistsRoot = self.send(request)?;
+
+        Ok(response.into())
+    }
+
+    pub fn get_watchlist(&self, watchlist_id: String) -> Result<WatchlistRoot> {
+        let request = self.build_request_get(
+            &format!("watchlists/{}", watchlist_id),
+            None::<()>,
+            None::<()>,
+        );
+        let response: NaiveWatchlistRoot = self.send(request)?;
+
+        Ok(response.into())
+    }
+
+    pub fn create_watchlist(&self, name: String, symbols: Vec<String>) -> Result<WatchlistRoot> {
+        let body = Body::new(Some(name), Some(symbols));
+
+        let request = self.build_request_post("watchlists", Some(body), None::<()>);
+        let response: NaiveWatchlistRoot = self.send(request)?;
+
+        Ok(response.into())
+    }
+
+    /// Renames the watchlist and, when `symbols` is given, replaces its symbols.
+    pub fn update_watchlist(
+        &self,
+        watchlist_id: String,
+        name: String,
+        symbols: Option<Vec<String>>,
+    ) -> Result<WatchlistRoot> {
+        let body = Body::new(Some(name), symbols);
+
+        let request = self.build_request_put(
+            &format!("watchlists/{}", watchlist_id),
+            Some(body),
+            None::<()>,
+        );
+        let response: NaiveWatchlistRoot = self.send(request)?;
+
+        Ok(response.into())
+    }
+
+    /// Returns the remaining watchlists.
+    pub fn delete_watchlist(&self, watchlist_id: String) -> Result<WatchlistsRoot> {
+        let request = self.build_request_del(&format!("watchlists/{}", watchlist_id));
+        let response: NaiveWatchlistsRoot = self.send(request)?;
+
+        Ok(response.into())
+    }
+
+    pub fn add_watchlist_symbols(
+        &self,
+        watchlist_id: String,
+        symbols: Vec<String>,
+    ) -> Result<WatchlistRoot> {
+        let body = Body::new(None, Some(symbols));
+
+        let request = self.build_request_post(
+            &format!("watchlists/{}/symbols", watchlist_id),
+            Some(body),
+            None::<()>,
+        );
+        let response: NaiveWatchlistRoot = self.send(request)?;
+
+        Ok(response.into())
+    }
+
+    pub fn remove_watchlist_symbol(
+        &self,
+        watchlist_id: String,
+        symbol: String,
+    ) -> Result<WatchlistRoot> {
+        let request =
+            self.build_request_del(&format!("watchlists/{}/symbols/{}", watchlist_id, symbol));
+        let response: NaiveWatchlistRoot = self.send(request)?;
+
+        Ok(response.into())
+    }
+
+    /// Quotes every symbol on the watchlist with
+    /// [`get_quotes_bulk`](Self::get_quotes_bulk), so long lists stay out of the URL.
+    pub fn get_watchlist_quotes(
+        &self,
+        watchlist_id: String,
+        greeks: Option<bool>,
+    ) -> Result<GetQuotes> {
+        let symbols = self.get_watchlist(watchlist_id)?.watchlist.symbols();
+        self.get_quotes_bulk(symbols, greeks)
+    }
+}
+
+impl TradierClient {
+    pub async fn get_watchlists(&self) -> Result<WatchlistsRoot> {
+        let request = self.build_request_get("watchlists", None::<()>, None::<()>);
+        let response: NaiveWatchlistsRoot = self.send(request).await?;
+
+        Ok(response.into())
+    }
+
+    pub async fn get_watchlist(&self, watchlist_id: String) -> Result<WatchlistRoot> {
+        let request = self.build_request_get(
+            &format!("watchlists/{}", watchlist_id),
+            None::<()>,
+            None::<()>,
+        );
+        let response: NaiveWatchlistRoot = self.send(request).await?;
+
+        Ok(response.into())
+    }
+
+    pub async fn create_watchlist(
+        &self,
+        name: String,
+        symbols: Vec<String>,
+    ) -> Result<WatchlistRoot> {
+        let body = Body::new(Some(name), Some(symbols));
+
+        let request = self.build_request_post("watchlists", Some(body), None::<()>);
+        let response: NaiveWatchlistRoot = self.send(request).await?;
+
+        Ok(response.into())
+    }
+
+    /// Renames the watchlist and, when `symbols` is given, replaces its symbols.
+    pub async fn update_watchlist(
+        &self,
+        watchlist_id: String,
+        name: String,
+        symbols: Option<Vec<String>>,
+    ) -> Result<WatchlistRoot> {
+        let body = Body::new(Some(name), symbols);
+
+        let request = self.build_request_put(
+            &format!("watchlists/{}", watchlist_id),
+            Some(body),
+            None::<()>,
+        );
+        let response: NaiveWatchlistRoot = self.send(request).await?;
+
+        Ok(response.into())
+    }
+
+    /// Returns the remaining watchlists.
+    pub async fn delete_watchlist(&self, watchlist_id: String) -> Result<WatchlistsRoot> {
+        let request = self.build_request_del(&format!("watchlists/{}", watchlist_id));
+        let response: NaiveWatchlistsRoot = self.send(request).await?;
+
+        Ok(response.into())
+    }
+
+    pub async fn add_watchlist_symbols(
+        &self,
+        watchlist_id: String,
+        symbols: Vec<String>,
+    ) -> Result<WatchlistRoot> {
+        let body = Body::new(None, Some(symbols));
+
+        let request = self.build_request_post(
+            &format!("watchlists/{}/symbols", watchlist_id),
+            Some(body),
+            None::<()>,
+        );
+        let response: NaiveWatchlistRoot = self.send(request).await?;
+
+        Ok(response.into())
+    }
+
+    pub async fn remove_watchlist_symbol(
+        &self,
+        watchlist_id: String,
+        symbol: String,
+    ) -> Result<WatchlistRoot> {
+        let request =
+            self.build_request_del(&format!("watchlists/{}/symbols/{}", watchlist_id, symbol));
+        let response: NaiveWatchlistRoot = self.send(request).await?;
+
+        Ok(response.into())
+    }
+
+    /// Quotes every symbol on the watchlist with
+    /// [`get_quotes_bulk`](Self::get_quotes_bulk), so long lists stay out of the URL.
+    pub async fn get_watchlist_quotes(
+        &self,
+        watchlist_id: String,
+        greeks: Option<bool>,
+    ) -> Result<GetQuotes> {
+        let symbols = self.get_watchlist(watchlist_id).await?.watchlist.symbols();
+        self.get_quotes_bulk(symbols, greeks).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::mock;
+
+    #[cfg(feature = "blocking")]
+    use crate::blocking;
+    use crate::{TradierClient, TradierConfig};
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn test_get_watchlists() {
+        let client = blocking::TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        let _m = mock("GET", "/v1/watchlists")
+            .with_status(200)
+            .with_body(include_str!("test_requests/get_watchlists.json"))
+            .create();
+        let response = client.get_watchlists().unwrap();
+        assert_eq!(response.watchlists.watchlist.len(), 2);
+
+        let _m = mock("DELETE", "/v1/watchlists/my-watchlist")
+            .with_status(200)
+            .with_body(include_str!("test_requests/delete_watchlist.json"))
+            .create();
+        let response = client.delete_watchlist("my-watchlist".into()).unwrap();
+        assert_eq!(response.watchlists.watchlist[0].id, "default");
+    }
+
+    #[tokio::test]
+    async fn test_create_watchlist() {
+        let _m = mock("POST", "/v1/watchlists")
+            .match_body("name=Tech&symbols=AAPL")
+            .with_status(200)
+            .with_body(include_str!("test_requests/post_watchlist.json"))
+            .create();
+
+        let client = TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        let response = client
+            .create_watchlist("Tech".into(), vec!["AAPL".into()])
+            .await
+            .unwrap();
+        assert_eq!(response.watchlist.id, "tech");
+        assert_eq!(response.watchlist.symbols(), vec!["AAPL"]);
+    }
+
+    #[tokio::test]
+    async fn test_update_watchlist() {
+        let _m = mock("PUT", "/v1/watchlists/tech")
+            .match_body("name=Tech+Giants&symbols=AAPL%2CMSFT")
+            .with_status(200)
+            .with_body(include_str!("test_requests/put_watchlist.json"))
+            .create();
+
+        let client = TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        let response = client
+            .update_watchlist(
+                "tech".into(),
+                "Tech Giants".into(),
+                Some(vec!["AAPL".into(), "MSFT".into()]),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.watchlist.name, "Tech Giants");
+        assert_eq!(response.watchlist.symbols(), vec!["AAPL", "MSFT"]);
+    }
+
+    #[tokio::test]
+    async fn test_edit_watchlist_symbols() {
+        let _add = mock("POST", "/v1/watchlists/my-watchlist/symbols")
+            .match_body("symbols=NFLX")
+            .with_status(200)
+            .with_body(include_str!("test_requests/post_watchlist_symbols.json"))
+            .create();
+        let _remove = mock("DELETE", "/v1/watchlists/my-watchlist/symbols/IBM")
+            .with_status(200)
+            .with_body(include_str!("test_requests/delete_watchlist_symbol.json"))
+            .create();
+
+        let client = TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        let response = client
+            .add_watchlist_symbols("my-watchlist".into(), vec!["NFLX".into()])
+            .await
+            .unwrap();
+        assert_eq!(response.watchlist.symbols(), vec!["AAPL", "IBM", "NFLX"]);
+
+        let response = client
+            .remove_watchlist_symbol("my-watchlist".into(), "IBM".into())
+            .await
+            .unwrap();
+        assert_eq!(response.watchlist.symbols(), vec!["AAPL"]);
+    }
+
+    #[tokio::test]
+    async fn test_get_watchlist_quotes() {
+        let _watchlist = mock("GET", "/v1/watchlists/my-watchlist")
+            .with_status(200)
+            .with_body(include_str!("test_requests/get_watchlist.json"))
+            .create();
+        let _quotes = mock("POST", "/v1/markets/quotes")
+            .match_body("symbols=AAPL%2CIBM&greeks=false")
+            .with_status(200)
+            .with_body(include_str!(
+                "../market_data/test_requests/get_quotes_unmatched.json"
+            ))
+            .create();
+
+        let client = TradierClient::new(TradierConfig::new("xxx", mockito::server_url()));
+
+        let response = client
+            .get_watchlist_quotes("my-watchlist".into(), None)
+            .await
+            .unwrap();
+        assert_eq!(response.quotes.quote.len(), 1);
+    }
+}